@@ -0,0 +1,88 @@
+/// Allows you to apply any function given as a parameter to the mutably borrowed value in place.
+///
+/// `Applicable::apply` takes the ownership of the value. The method of this trait only needs the
+/// mutable reference, so struct fields, iterator items and lock guards can be chained as well.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// struct Kennel {
+///     names: Vec<&'static str>,
+/// }
+/// let mut kennel = Kennel { names: vec![] };
+/// kennel
+///     .names
+///     .apply_mut(|it| it.push("Pochi"))
+///     .apply_mut(|it| it.push("Hachi"));
+/// assert_eq!(kennel.names, vec!["Pochi", "Hachi"]);
+/// ```
+pub trait ApplicableMut {
+    /// Apply the function given as a parameter to self in place, and return the mutable
+    /// reference to self.
+    fn apply_mut<F, R>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self) -> R;
+}
+
+impl<T: ?Sized> ApplicableMut for T {
+    fn apply_mut<F, R>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        f(self);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::fmt::Write;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_apply_mut() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut map = HashMap::new();
+        map.apply_mut(|it| it.insert(1, "one"))
+            .apply_mut(|it| it.insert(2, "two"));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_mut_items() {
+        let mut names = vec![String::from("Pochi"), String::from("Hachi")];
+        for name in names.iter_mut() {
+            name.apply_mut(|it| it.push('!'))
+                .apply_mut(|it| it.insert(0, '*'));
+        }
+        assert_eq!(names, vec!["*Pochi!", "*Hachi!"]);
+        let numbers = Mutex::new(vec![1]);
+        numbers.lock().unwrap().apply_mut(|it| it.push(2));
+        assert_eq!(numbers.into_inner().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_mut_unsized() {
+        let mut numbers = vec![3, 1, 2];
+        numbers[..]
+            .apply_mut(|it| it.sort())
+            .apply_mut(|it| it.reverse());
+        assert_eq!(numbers, vec![3, 2, 1]);
+
+        let mut name = String::from("pochi");
+        name.as_mut_str().apply_mut(|it| it.make_ascii_uppercase());
+        assert_eq!(name, "POCHI");
+
+        let mut log = String::new();
+        let writer: &mut dyn Write = &mut log;
+        writer
+            .apply_mut(|it| it.write_str("Pochi"))
+            .apply_mut(|it| it.write_char('!'));
+        assert_eq!(log, "Pochi!");
+    }
+}
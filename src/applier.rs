@@ -0,0 +1,273 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+type Function<T> = Box<dyn Fn(&mut T) + Send + Sync>;
+
+/// The reusable function which can be applied to any number of values.
+///
+/// It is a first-class value representing a mutation, which can be named, cloned cheaply, stored
+/// in collections and shared between threads. Pass [`Applier::as_fn`] to
+/// [`Applicable::apply`](crate::Applicable::apply), or call it directly with the `nightly`
+/// feature, which implements the `Fn` traits.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let appliers = vec![
+///     Applier::named("bark", |it: &mut String| it.push_str(" bow-wow")),
+///     Applier::new(|it: &mut String| it.push('!')),
+/// ];
+/// let dog = String::from("Pochi").apply(|it| appliers.iter().for_each(|f| f.apply_to(it)));
+/// assert_eq!(dog, "Pochi bow-wow!");
+/// assert_eq!(appliers[0].name(), Some("bark"));
+/// assert_eq!(appliers[1].name(), None);
+/// ```
+pub struct Applier<T: ?Sized> {
+    name: Option<Cow<'static, str>>,
+    steps: Arc<[Function<T>]>,
+}
+
+impl<T: ?Sized> Applier<T> {
+    /// Create a new applier without name.
+    pub fn new<F, R>(f: F) -> Self
+    where
+        F: Fn(&mut T) -> R + Send + Sync + 'static,
+    {
+        ApplierBuilder::new().step(f).build()
+    }
+
+    /// Create a new applier with the name, e.g. for logging.
+    pub fn named<N, F, R>(name: N, f: F) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        F: Fn(&mut T) -> R + Send + Sync + 'static,
+    {
+        Self {
+            name: Some(name.into()),
+            ..Self::new(f)
+        }
+    }
+
+    /// Create a new builder to assemble an applier from multiple steps.
+    pub fn builder() -> ApplierBuilder<T> {
+        ApplierBuilder::new()
+    }
+
+    /// Return the name of the applier, or `None` if it has no name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Apply the function to the value given as a parameter.
+    pub fn apply_to(&self, value: &mut T) {
+        for step in self.steps.iter() {
+            step(value);
+        }
+    }
+
+    /// Return the function which applies self, to be passed to the apply methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let bark = Applier::new(|it: &mut Vec<&str>| it.push("bow-wow"));
+    /// let pochi = vec!["Pochi"].apply(bark.as_fn());
+    /// let hachi = vec!["Hachi"].apply(bark.as_fn());
+    /// assert_eq!(pochi, vec!["Pochi", "bow-wow"]);
+    /// assert_eq!(hachi, vec!["Hachi", "bow-wow"]);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(&mut T) + '_ {
+        move |it| self.apply_to(it)
+    }
+}
+
+impl<T: ?Sized> Clone for Applier<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            steps: Arc::clone(&self.steps),
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Applier<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Applier")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The builder which assembles an [`Applier`] from steps and conditional steps.
+///
+/// The steps are applied in the order they were added every time the built applier is applied.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let verbose = true;
+/// let format = Applier::builder()
+///     .step(|it: &mut String| it.make_ascii_uppercase())
+///     .when(verbose, |it| it.push_str(" (dog)"))
+///     .when_with(|it| !it.ends_with('!'), |it| it.push('!'))
+///     .build();
+/// let pochi = String::from("Pochi").apply(format.as_fn());
+/// assert_eq!(pochi, "POCHI (dog)!");
+/// ```
+pub struct ApplierBuilder<T: ?Sized> {
+    steps: Vec<Function<T>>,
+}
+
+impl<T: ?Sized> ApplierBuilder<T> {
+    /// Create a new builder without any step.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Add the step which is always applied.
+    pub fn step<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut T) -> R + Send + Sync + 'static,
+    {
+        let mut receiver = self;
+        receiver.steps.push(Box::new(move |it: &mut T| {
+            f(it);
+        }));
+        receiver
+    }
+
+    /// Add the step only if the condition is true.
+    pub fn when<F, R>(self, cond: bool, f: F) -> Self
+    where
+        F: Fn(&mut T) -> R + Send + Sync + 'static,
+    {
+        if cond {
+            self.step(f)
+        } else {
+            self
+        }
+    }
+
+    /// Add the step which is applied only to the values for which the predicate returns true.
+    ///
+    /// The predicate is evaluated every time the built applier is applied.
+    pub fn when_with<P, F, R>(self, pred: P, f: F) -> Self
+    where
+        P: Fn(&T) -> bool + Send + Sync + 'static,
+        F: Fn(&mut T) -> R + Send + Sync + 'static,
+    {
+        self.step(move |it: &mut T| {
+            if pred(it) {
+                f(it);
+            }
+        })
+    }
+
+    /// Build the applier which applies all steps in order.
+    pub fn build(self) -> Applier<T> {
+        Applier {
+            name: None,
+            steps: Arc::from(self.steps),
+        }
+    }
+}
+
+impl<T: ?Sized> Default for ApplierBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for ApplierBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApplierBuilder")
+            .field("steps", &self.steps.len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> FnOnce<(&'a mut T,)> for Applier<T> {
+    type Output = ();
+
+    extern "rust-call" fn call_once(self, (value,): (&'a mut T,)) {
+        self.apply_to(value)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> FnMut<(&'a mut T,)> for Applier<T> {
+    extern "rust-call" fn call_mut(&mut self, (value,): (&'a mut T,)) {
+        self.apply_to(value)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> Fn<(&'a mut T,)> for Applier<T> {
+    extern "rust-call" fn call(&self, (value,): (&'a mut T,)) {
+        self.apply_to(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Applicable;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_applier() {
+        let push_one = Applier::named(String::from("push one"), |it: &mut Vec<i32>| it.push(1));
+        let cloned = push_one.clone();
+        assert_eq!(cloned.name(), Some("push one"));
+        let numbers = vec![0].apply(push_one.as_fn()).apply(push_one.as_fn());
+        assert_eq!(numbers, vec![0, 1, 1]);
+        let numbers = std::thread::spawn(move || Vec::new().apply(cloned.as_fn()))
+            .join()
+            .unwrap();
+        assert_eq!(numbers, vec![1]);
+        assert!(format!("{:?}", push_one).contains("push one"));
+    }
+
+    #[test]
+    fn test_applier_unsized() {
+        let sort = Applier::new(<[i32]>::sort);
+        let mut numbers = vec![3, 1, 2];
+        sort.apply_to(&mut numbers);
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_applier_builder() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(3, "three");
+        let applier = ApplierBuilder::new()
+            .step(|it: &mut HashMap<i32, &str>| it.insert(1, "one"))
+            .when(false, |it| it.insert(2, "two"))
+            .when(true, |it| it.insert(3, "three"))
+            .when_with(|it| it.contains_key(&0), |it| it.clear())
+            .build();
+        let map = HashMap::new().apply(applier.as_fn());
+        assert_eq!(map, exact_map);
+        let map = HashMap::new()
+            .apply(|it| it.insert(0, "zero"))
+            .apply(applier.as_fn());
+        assert_eq!(map, HashMap::new());
+        assert!(Applier::<Vec<i32>>::builder().build().name().is_none());
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_applier_fn_traits() {
+        let push = Applier::new(|it: &mut Vec<i32>| it.push(1));
+        let numbers = Vec::new().apply(push.clone()).apply(&push);
+        assert_eq!(numbers, vec![1, 1]);
+    }
+}
@@ -0,0 +1,210 @@
+//! The free-function forms of the apply methods.
+//!
+//! They behave the same as the methods of [`Applicable`](crate::Applicable), and are useful for
+//! the function-call style, macro expansions and higher-order composition, e.g. passing
+//! `apply_fn::apply` to `Iterator::map`. The module also has the combinators which compose
+//! functions into reusable pipelines before applying them to any value.
+//!
+//! # Examples
+//!
+//! ```
+//! use apply_method::apply_fn::{apply, with};
+//! let names = apply(vec!["Pochi"], |it| it.push("Hachi"));
+//! assert_eq!(names, vec!["Pochi", "Hachi"]);
+//! let mut names = names;
+//! let count = with(&mut names, |it| {
+//!     it.push("Shiro");
+//!     it.len()
+//! });
+//! assert_eq!(count, 3);
+//! ```
+
+/// Apply the function given as a parameter to the value, and return the value.
+///
+/// This is the same as [`Applicable::apply`](crate::Applicable::apply).
+///
+/// # Example
+///
+/// ```
+/// use apply_method::apply_fn::apply;
+/// let paths: Vec<String> = vec!["src", "tests"]
+///     .into_iter()
+///     .map(String::from)
+///     .map(|it| apply(it, |it| it.push('/')))
+///     .collect();
+/// assert_eq!(paths, vec!["src/", "tests/"]);
+/// ```
+pub fn apply<T, F, R>(value: T, f: F) -> T
+where
+    F: FnOnce(&mut T) -> R,
+{
+    let mut receiver = value;
+    f(&mut receiver);
+    receiver
+}
+
+/// Apply the function given as a parameter to the borrowed value, and return the result of the
+/// function.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::apply_fn::with;
+/// let mut name = String::from("Pochi");
+/// let len = with(&mut name, |it| {
+///     it.push('!');
+///     it.len()
+/// });
+/// assert_eq!((name.as_str(), len), ("Pochi!", 6));
+/// ```
+pub fn with<T, F, R>(value: &mut T, f: F) -> R
+where
+    T: ?Sized,
+    F: FnOnce(&mut T) -> R,
+{
+    f(value)
+}
+
+/// Bind the parameter to the function, and return the function which only takes the value.
+///
+/// This allows you to pass the methods taking a parameter to
+/// [`Applicable::apply`](crate::Applicable::apply) without writing closures, and to store
+/// pre-bound operations.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::*;
+/// use apply_method::apply_fn::{compose_all, with_param};
+/// use std::path::PathBuf;
+/// let path = PathBuf::new().apply(with_param(PathBuf::push, "src"));
+/// assert_eq!(path, PathBuf::from("src"));
+/// let steps = vec![with_param(PathBuf::push, "src"), with_param(PathBuf::push, "lib.rs")];
+/// let path = PathBuf::new().apply(compose_all(steps));
+/// assert_eq!(path, PathBuf::from("src/lib.rs"));
+/// ```
+pub fn with_param<T, F, P, R>(f: F, p: P) -> impl FnOnce(&mut T) -> R
+where
+    T: ?Sized,
+    F: FnOnce(&mut T, P) -> R,
+{
+    move |it| f(it, p)
+}
+
+/// Compose two functions into a single function which applies them in order.
+///
+/// The results of the functions are discarded, so the composed function can be passed to
+/// [`Applicable::apply`](crate::Applicable::apply) or to `compose` again.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::*;
+/// use apply_method::apply_fn::compose;
+/// use std::path::PathBuf;
+/// let to_lib = compose(|it: &mut PathBuf| it.push("lib"), |it| it.set_extension("rs"));
+/// let path = PathBuf::from("src").apply(to_lib);
+/// assert_eq!(path, PathBuf::from("src/lib.rs"));
+/// ```
+pub fn compose<T, F, G, R, S>(f: F, g: G) -> impl FnOnce(&mut T)
+where
+    T: ?Sized,
+    F: FnOnce(&mut T) -> R,
+    G: FnOnce(&mut T) -> S,
+{
+    move |it| {
+        f(it);
+        g(it);
+    }
+}
+
+/// Compose all functions into a single function which applies them in order.
+///
+/// The functions must have the same type, e.g. function pointers or boxed closures like
+/// `LocalApplyFn`.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::*;
+/// use apply_method::apply_fn::compose_all;
+/// let normalize = compose_all([str::make_ascii_lowercase as fn(&mut str), |it| {
+///     if let Some(first) = it.get_mut(..1) {
+///         first.make_ascii_uppercase();
+///     }
+/// }]);
+/// let name = String::from("pOCHI").apply(|it| normalize(it.as_mut_str()));
+/// assert_eq!(name, "Pochi");
+/// ```
+pub fn compose_all<T, I, R>(fs: I) -> impl FnOnce(&mut T)
+where
+    T: ?Sized,
+    I: IntoIterator,
+    I::Item: FnOnce(&mut T) -> R,
+{
+    move |it| {
+        for f in fs {
+            f(it);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = apply(HashMap::new(), |it| it.insert(1, "one"));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_with() {
+        let mut map = HashMap::new();
+        let old = with(&mut map, |it| it.insert(1, "one"));
+        assert_eq!(old, None);
+        let old = with(&mut map, |it| it.insert(1, "uno"));
+        assert_eq!(old, Some("one"));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_compose() {
+        let push_then_pop = compose(
+            |it: &mut Vec<i32>| it.push(1),
+            compose(|it: &mut Vec<i32>| it.push(2), |it| it.pop()),
+        );
+        let numbers = apply(vec![0], push_then_pop);
+        assert_eq!(numbers, vec![0, 1]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_compose_all() {
+        let steps: Vec<crate::LocalApplyFn<'_, Vec<i32>>> = vec![
+            Box::new(|it| it.push(1)),
+            Box::new(|it| it.push(2)),
+            Box::new(|it| it.reverse()),
+        ];
+        let numbers = apply(vec![0], compose_all(steps));
+        assert_eq!(numbers, vec![2, 1, 0]);
+        let numbers = apply(vec![1], compose_all(Vec::<fn(&mut Vec<i32>)>::new()));
+        assert_eq!(numbers, vec![1]);
+    }
+
+    #[test]
+    fn test_with_param() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let insert = with_param(|it: &mut HashMap<_, _>, (k, v)| it.insert(k, v), (1, "one"));
+        let map = apply(HashMap::new(), insert);
+        assert_eq!(map, exact_map);
+        let mut numbers = vec![1, 2];
+        let removed = with(&mut numbers, with_param(Vec::remove, 0));
+        assert_eq!((removed, numbers), (1, vec![2]));
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use crate::ApplyFn;
+
+enum Message<T> {
+    Apply(ApplyFn<T>),
+    Stop,
+}
+
+/// The handle to the value owned by a background thread, which serializes every apply through a
+/// queue.
+///
+/// Functions sent through the handle are applied to the value one by one in the order they are
+/// sent, so the value can be shared between threads like an actor without any lock.
+///
+/// # Panics
+///
+/// If one of the sent functions panics, the background thread stops, and the following
+/// [`query`](ApplyHandle::query) and [`join`](ApplyHandle::join) panic. Functions sent through
+/// [`send`](ApplyHandle::send) after that are dropped silently.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let handle = ApplyHandle::new(Vec::new());
+/// handle.send(|it| it.push("Pochi")).send(|it| it.push("Hachi"));
+/// assert_eq!(handle.query(|it| it.len()), 2);
+/// assert_eq!(handle.join(), vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub struct ApplyHandle<T> {
+    sender: Sender<Message<T>>,
+    thread: Option<JoinHandle<T>>,
+}
+
+impl<T: Send + 'static> ApplyHandle<T> {
+    /// Move the value to a new background thread and return the handle to it.
+    pub fn new(value: T) -> Self {
+        let (sender, receiver) = mpsc::channel::<Message<T>>();
+        let thread = thread::spawn(move || {
+            let mut value = value;
+            while let Ok(Message::Apply(job)) = receiver.recv() {
+                job(&mut value);
+            }
+            value
+        });
+        Self {
+            sender,
+            thread: Some(thread),
+        }
+    }
+
+    /// Queue the function given as a parameter to be applied to the value, without waiting for
+    /// it.
+    ///
+    /// If the background thread has stopped because a function panicked, the function is dropped
+    /// without being applied, and the panic is reported by the following query or join instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let handle = ApplyHandle::new(0);
+    /// handle.send(|it| *it += 1).send(|it| *it *= 10);
+    /// assert_eq!(handle.join(), 10);
+    /// ```
+    pub fn send<F>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) + Send + 'static,
+    {
+        let _ = self.sender.send(Message::Apply(Box::new(f)));
+        self
+    }
+
+    /// Apply the function given as a parameter to the value after every queued function, and
+    /// wait for its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let handle = ApplyHandle::new(String::from("Pochi"));
+    /// handle.send(|it| it.push_str(" and Hachi"));
+    /// assert_eq!(handle.query(|it| it.clone()), "Pochi and Hachi");
+    /// ```
+    pub fn query<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        let _ = self.sender.send(Message::Apply(Box::new(move |it: &mut T| {
+            let _ = sender.send(f(it));
+        })));
+        receiver
+            .recv()
+            .expect("the thread owning the value has panicked")
+    }
+
+    /// Return the sender which can send functions to the value from other threads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let handle = ApplyHandle::new(0);
+    /// let workers: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let sender = handle.clone_sender();
+    ///         std::thread::spawn(move || {
+    ///             sender.send(|it| *it += 1).unwrap();
+    ///         })
+    ///     })
+    ///     .collect();
+    /// workers.into_iter().for_each(|it| it.join().unwrap());
+    /// assert_eq!(handle.join(), 4);
+    /// ```
+    pub fn clone_sender(&self) -> ApplySender<T> {
+        ApplySender {
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// Wait for every function queued so far to be applied, stop the background thread, and
+    /// return the value.
+    ///
+    /// Functions sent through the senders after this are not applied anymore.
+    pub fn join(mut self) -> T {
+        let _ = self.sender.send(Message::Stop);
+        match self.thread.take().map(JoinHandle::join) {
+            Some(Ok(value)) => value,
+            Some(Err(payload)) => std::panic::resume_unwind(payload),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T> Drop for ApplyHandle<T> {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = self.sender.send(Message::Stop);
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The sender which queues functions to the value owned by an [`ApplyHandle`].
+///
+/// Unlike the handle, it can be cloned and moved to other threads.
+pub struct ApplySender<T> {
+    sender: Sender<Message<T>>,
+}
+
+impl<T> Clone for ApplySender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T: Send + 'static> ApplySender<T> {
+    /// Queue the function given as a parameter to be applied to the value.
+    ///
+    /// An error is returned if the background thread has already stopped.
+    pub fn send<F>(&self, f: F) -> Result<&Self, SendError>
+    where
+        F: FnOnce(&mut T) + Send + 'static,
+    {
+        self.sender
+            .send(Message::Apply(Box::new(f)))
+            .map_err(|_| SendError)?;
+        Ok(self)
+    }
+}
+
+/// The error returned when a function is sent to the value whose background thread has stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError;
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a stopped apply handle")
+    }
+}
+
+impl std::error::Error for SendError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_handle() {
+        let handle = ApplyHandle::new(Vec::new());
+        handle.send(|it| it.push(1)).send(|it| it.push(2));
+        assert_eq!(handle.query(|it| it.len()), 2);
+        handle.send(|it| it.push(3));
+        assert_eq!(handle.join(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_apply_handle_from_threads() {
+        let handle = ApplyHandle::new(Vec::new());
+        let workers: Vec<_> = (0..4)
+            .map(|n| {
+                let sender = handle.clone_sender();
+                std::thread::spawn(move || {
+                    sender.send(move |it| it.push(n)).unwrap();
+                })
+            })
+            .collect();
+        workers.into_iter().for_each(|it| it.join().unwrap());
+        let mut numbers = handle.join();
+        numbers.sort();
+        assert_eq!(numbers, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the thread owning the value has panicked")]
+    fn test_apply_handle_panicked_case() {
+        let handle = ApplyHandle::new(0);
+        handle
+            .send(|_| panic!("failed to apply"))
+            .send(|it| *it += 1);
+        handle.query(|it| *it);
+    }
+
+    #[test]
+    fn test_apply_sender_after_join() {
+        let handle = ApplyHandle::new(0);
+        let sender = handle.clone_sender();
+        sender.send(|it| *it += 1).unwrap();
+        assert_eq!(handle.join(), 1);
+        assert_eq!(sender.send(|it| *it += 1).err(), Some(SendError));
+    }
+}
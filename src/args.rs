@@ -0,0 +1,45 @@
+/// The tuple of the arguments given to [`Applicable::apply_with_args`](crate::Applicable::apply_with_args).
+///
+/// It is implemented for the tuples of one to four elements, and calls the function with the
+/// receiver followed by each element of the tuple.
+pub trait ApplyArgs<S: ?Sized, F, R> {
+    /// Call the function given as a parameter with the receiver and the arguments.
+    fn apply_to(self, receiver: &mut S, f: F) -> R;
+}
+
+macro_rules! impl_apply_args {
+    ($($arg:ident),+) => {
+        impl<S: ?Sized, F, R, $($arg),+> ApplyArgs<S, F, R> for ($($arg,)+)
+        where
+            F: FnOnce(&mut S, $($arg),+) -> R,
+        {
+            #[allow(non_snake_case)]
+            fn apply_to(self, receiver: &mut S, f: F) -> R {
+                let ($($arg,)+) = self;
+                f(receiver, $($arg),+)
+            }
+        }
+    };
+}
+
+impl_apply_args!(A);
+impl_apply_args!(A, B);
+impl_apply_args!(A, B, C);
+impl_apply_args!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_to() {
+        let mut numbers = vec![1, 3];
+        (1, 2).apply_to(&mut numbers, Vec::insert);
+        let sum = (10, 20, 30).apply_to(&mut numbers, |it: &mut Vec<i32>, a, b, c| {
+            it.push(a + b + c);
+            it.iter().sum::<i32>()
+        });
+        assert_eq!(numbers, vec![1, 2, 3, 60]);
+        assert_eq!(sum, 66);
+    }
+}
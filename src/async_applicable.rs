@@ -0,0 +1,623 @@
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use crate::Elapsed;
+use alloc::boxed::Box;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Poll;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use core::time::Duration;
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+
+/// An owned, pinned and boxed future which can be sent to other threads.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Allows you to apply any asynchronous function given as a parameter to the object.
+///
+/// The function receives a mutable reference to the object and returns a boxed future, so the
+/// object can be configured with awaits in the middle of a chain.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// # futures::executor::block_on(async {
+/// async fn fetch_name() -> String {
+///     "Pochi".to_string()
+/// }
+/// let names = Vec::new()
+///     .apply_async(|it| Box::pin(async move { it.push(fetch_name().await) }))
+///     .await;
+/// assert_eq!(names, vec!["Pochi".to_string()]);
+/// # });
+/// ```
+///
+pub trait AsyncApplicable: Sized + Send {
+    /// Apply the asynchronous function given as a parameter to self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src/lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async(|it| Box::pin(async move { it.push("src") }))
+    ///     .await
+    ///     .apply_async(|it| Box::pin(async move { it.push("lib.rs") }))
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    ///
+    fn apply_async<'a, F, R>(self, f: F) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self) -> BoxFuture<'b, R> + Send + 'a;
+
+    /// Apply the asynchronous function with one parameter given as a parameter to self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src/lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async_with_param(|it, p| Box::pin(async move { it.push(p) }), "src/lib.rs")
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    ///
+    fn apply_async_with_param<'a, F, P, R>(self, f: F, p: P) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a;
+
+    /// Apply apply_async_with_param repeatedly to multiple parameters.
+    ///
+    /// The function is awaited for each parameter in order before the next one is applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src");
+    /// exact_path.push("lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async_with_params(
+    ///         |it, p| Box::pin(async move { it.push(p) }),
+    ///         vec!["src", "lib.rs"],
+    ///     )
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    fn apply_async_with_params<'a, F, P, I, R>(self, f: F, p: I) -> BoxFuture<'a, Self>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a;
+
+    /// Apply apply_async_with_param repeatedly to multiple parameters until the cancellation
+    /// future completes.
+    ///
+    /// The cancellation future is checked before each parameter, so the application is
+    /// interrupted only between parameters. Self is returned with the number of applied
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// # futures::executor::block_on(async {
+    /// let (numbers, applied) = Vec::new()
+    ///     .apply_async_with_params_until(
+    ///         |it, n| Box::pin(async move { it.push(n) }),
+    ///         vec![1, 2, 3],
+    ///         std::future::ready(()),
+    ///     )
+    ///     .await;
+    /// assert_eq!(numbers, Vec::<i32>::new());
+    /// assert_eq!(applied, 0);
+    /// # });
+    /// ```
+    fn apply_async_with_params_until<'a, F, P, I, R, C>(
+        self,
+        f: F,
+        p: I,
+        cancel: C,
+    ) -> BoxFuture<'a, (Self, usize)>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a,
+        C: Future + Send + 'a;
+
+    /// Apply the function with one parameter given as a parameter to self repeatedly to every
+    /// parameter yielded by the stream.
+    ///
+    /// The parameters are applied in order until the stream ends, and then self is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashMap;
+    /// # futures::executor::block_on(async {
+    /// let updates = futures::stream::iter(vec![(1, "one"), (2, "two"), (1, "uno")]);
+    /// let map = HashMap::new()
+    ///     .apply_with_param_stream(|it, (k, v)| it.insert(k, v), updates)
+    ///     .await;
+    /// assert_eq!(map[&1], "uno");
+    /// assert_eq!(map[&2], "two");
+    /// # });
+    /// ```
+    #[cfg(feature = "stream")]
+    fn apply_with_param_stream<'a, F, S, R>(self, f: F, stream: S) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: FnMut(&mut Self, S::Item) -> R + Send + 'a,
+        S: Stream + Send + 'a;
+
+    /// Apply the asynchronous function given as a parameter to self, cancelling it if it does
+    /// not complete within the duration.
+    ///
+    /// If the deadline expires, self is returned in the error in the state left by the
+    /// cancelled function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use core::time::Duration;
+    /// # #[cfg(feature = "tokio")]
+    /// # use tokio::time::sleep;
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # use async_std::task::sleep;
+    /// # #[cfg(feature = "tokio")]
+    /// # let block_on = |future| tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(future);
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # use async_std::task::block_on;
+    /// # block_on(async {
+    /// let numbers = Vec::new()
+    ///     .apply_async_timeout(Duration::from_millis(10), |it| {
+    ///         Box::pin(async move {
+    ///             it.push(1);
+    ///             sleep(Duration::from_secs(10)).await;
+    ///             it.push(2);
+    ///         })
+    ///     })
+    ///     .await;
+    /// assert_eq!(numbers.unwrap_err().value, vec![1]);
+    /// # });
+    /// ```
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_async_timeout<'a, F, R>(
+        self,
+        duration: Duration,
+        f: F,
+    ) -> BoxFuture<'a, Result<Self, Elapsed<Self>>>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self) -> BoxFuture<'b, R> + Send + 'a;
+
+    /// Apply apply_async_with_param repeatedly to multiple parameters, waiting for the interval
+    /// between the applications.
+    ///
+    /// This is useful to limit the rate when each parameter triggers a request to a remote
+    /// service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::time::{Duration, Instant};
+    /// # #[cfg(feature = "tokio")]
+    /// # let block_on = |future| tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(future);
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # use async_std::task::block_on;
+    /// # block_on(async {
+    /// let started = Instant::now();
+    /// let numbers = Vec::new()
+    ///     .apply_with_params_throttled(
+    ///         |it, n| Box::pin(async move { it.push(n) }),
+    ///         vec![1, 2, 3],
+    ///         Duration::from_millis(10),
+    ///     )
+    ///     .await;
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert!(started.elapsed() >= Duration::from_millis(20));
+    /// # });
+    /// ```
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_with_params_throttled<'a, F, P, I, R>(
+        self,
+        f: F,
+        p: I,
+        interval: Duration,
+    ) -> BoxFuture<'a, Self>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a;
+
+    /// Apply the blocking function given as a parameter to self on the blocking thread pool of
+    /// the runtime.
+    ///
+    /// Self is moved to the thread which runs the function and returned when it completes, so
+    /// CPU-heavy or blocking steps don't stall the asynchronous runtime. If the function panics,
+    /// the panic is propagated to the awaiting task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// # #[cfg(feature = "tokio")]
+    /// # let block_on = |future| tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future);
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # use async_std::task::block_on;
+    /// # block_on(async {
+    /// let numbers = (1..=5).collect::<Vec<u64>>()
+    ///     .apply_blocking(|it| it.iter_mut().for_each(|n| *n = (1..=*n).product()))
+    ///     .await;
+    /// assert_eq!(numbers, vec![1, 2, 6, 24, 120]);
+    /// # });
+    /// ```
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_blocking<F, R>(self, f: F) -> BoxFuture<'static, Self>
+    where
+        Self: 'static,
+        F: FnOnce(&mut Self) -> R + Send + 'static;
+}
+
+impl<T: Send> AsyncApplicable for T {
+    fn apply_async<'a, F, R>(self, f: F) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self) -> BoxFuture<'b, R> + Send + 'a,
+    {
+        Box::pin(async move {
+            let mut receiver = self;
+            f(&mut receiver).await;
+            receiver
+        })
+    }
+
+    fn apply_async_with_param<'a, F, P, R>(self, f: F, p: P) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a,
+    {
+        Box::pin(async move {
+            let mut receiver = self;
+            f(&mut receiver, p).await;
+            receiver
+        })
+    }
+
+    fn apply_async_with_params<'a, F, P, I, R>(self, f: F, p: I) -> BoxFuture<'a, Self>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a,
+    {
+        let p = p.into_iter();
+        Box::pin(async move {
+            let mut receiver = self;
+            for param in p {
+                f(&mut receiver, param).await;
+            }
+            receiver
+        })
+    }
+
+    fn apply_async_with_params_until<'a, F, P, I, R, C>(
+        self,
+        f: F,
+        p: I,
+        cancel: C,
+    ) -> BoxFuture<'a, (Self, usize)>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a,
+        C: Future + Send + 'a,
+    {
+        let p = p.into_iter();
+        Box::pin(async move {
+            let mut receiver = self;
+            let mut cancel = Box::pin(cancel);
+            let mut applied = 0;
+            for param in p {
+                let cancelled =
+                    core::future::poll_fn(|cx| Poll::Ready(cancel.as_mut().poll(cx).is_ready()))
+                        .await;
+                if cancelled {
+                    break;
+                }
+                f(&mut receiver, param).await;
+                applied += 1;
+            }
+            (receiver, applied)
+        })
+    }
+
+    #[cfg(feature = "stream")]
+    fn apply_with_param_stream<'a, F, S, R>(self, mut f: F, stream: S) -> BoxFuture<'a, Self>
+    where
+        Self: 'a,
+        F: FnMut(&mut Self, S::Item) -> R + Send + 'a,
+        S: Stream + Send + 'a,
+    {
+        Box::pin(async move {
+            let mut receiver = self;
+            let mut stream = Box::pin(stream);
+            while let Some(param) = core::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await
+            {
+                f(&mut receiver, param);
+            }
+            receiver
+        })
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_async_timeout<'a, F, R>(
+        self,
+        duration: Duration,
+        f: F,
+    ) -> BoxFuture<'a, Result<Self, Elapsed<Self>>>
+    where
+        Self: 'a,
+        F: for<'b> FnOnce(&'b mut Self) -> BoxFuture<'b, R> + Send + 'a,
+    {
+        Box::pin(async move {
+            let mut receiver = self;
+            match crate::rt::timeout(duration, f(&mut receiver)).await {
+                Some(_) => Ok(receiver),
+                None => Err(Elapsed::new(receiver)),
+            }
+        })
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_with_params_throttled<'a, F, P, I, R>(
+        self,
+        f: F,
+        p: I,
+        interval: Duration,
+    ) -> BoxFuture<'a, Self>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'a,
+        Self: 'a,
+        F: for<'b> Fn(&'b mut Self, P) -> BoxFuture<'b, R> + Send + 'a,
+        P: Send + 'a,
+    {
+        let p = p.into_iter();
+        Box::pin(async move {
+            let mut receiver = self;
+            for (index, param) in p.enumerate() {
+                if index > 0 {
+                    crate::rt::sleep(interval).await;
+                }
+                f(&mut receiver, param).await;
+            }
+            receiver
+        })
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn apply_blocking<F, R>(self, f: F) -> BoxFuture<'static, Self>
+    where
+        Self: 'static,
+        F: FnOnce(&mut Self) -> R + Send + 'static,
+    {
+        Box::pin(crate::rt::spawn_blocking(move || {
+            crate::Applicable::apply(self, f)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_apply_async() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async(|it| Box::pin(async move { it.push("src/lib.rs") })),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_async_non_unit_return_method_case() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = block_on(async {
+            HashMap::new()
+                .apply_async(|it| Box::pin(async move { it.insert(1, "one") }))
+                .await
+                .apply_async(|it| Box::pin(async move { it.insert(2, "two") }))
+                .await
+        });
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_async_param() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async_with_param(|it, p| Box::pin(async move { it.push(p) }), "src/lib.rs"),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_async_params() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = block_on(HashMap::new().apply_async_with_params(
+            |it, (k, v)| Box::pin(async move { it.insert(k, v) }),
+            vec![(1, "one"), (2, "two")],
+        ));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_async_params_until() {
+        use futures::channel::oneshot;
+        let (sender, receiver) = oneshot::channel::<()>();
+        let sender = std::sync::Mutex::new(Some(sender));
+        let (numbers, applied) = block_on(vec![0].apply_async_with_params_until(
+            |it, n| {
+                if n == 2 {
+                    sender.lock().unwrap().take().unwrap().send(()).unwrap();
+                }
+                Box::pin(async move { it.push(n) })
+            },
+            vec![1, 2, 3, 4],
+            receiver,
+        ));
+        assert_eq!(numbers, vec![0, 1, 2]);
+        assert_eq!(applied, 2);
+    }
+
+    #[test]
+    fn test_apply_async_params_until_never_cancelled_case() {
+        let (numbers, applied) = block_on(vec![0].apply_async_with_params_until(
+            |it, n| Box::pin(async move { it.push(n) }),
+            vec![1, 2],
+            std::future::pending::<()>(),
+        ));
+        assert_eq!(numbers, vec![0, 1, 2]);
+        assert_eq!(applied, 2);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn test_apply_with_param_stream() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        exact_path.push("lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).apply_with_param_stream(
+                PathBuf::push,
+                futures::stream::iter(vec!["src", "lib.rs"]),
+            ),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn test_apply_with_param_stream_empty_case() {
+        let numbers =
+            block_on(vec![1].apply_with_param_stream(Vec::push, futures::stream::empty::<i32>()));
+        assert_eq!(numbers, vec![1]);
+    }
+
+    #[cfg(feature = "tokio")]
+    fn block_on_runtime<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    fn block_on_runtime<F: Future>(future: F) -> F::Output {
+        async_std::task::block_on(future)
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    #[test]
+    fn test_apply_async_timeout() {
+        use core::time::Duration;
+        let numbers =
+            block_on_runtime(vec![1].apply_async_timeout(Duration::from_secs(10), |it| {
+                Box::pin(async move { it.push(2) })
+            }));
+        assert_eq!(numbers, Ok(vec![1, 2]));
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    #[test]
+    fn test_apply_async_timeout_elapsed_case() {
+        use core::time::Duration;
+        let numbers =
+            block_on_runtime(vec![1].apply_async_timeout(Duration::from_millis(1), |it| {
+                Box::pin(async move {
+                    it.push(2);
+                    crate::rt::sleep(Duration::from_secs(10)).await;
+                    it.push(3);
+                })
+            }));
+        assert_eq!(numbers.unwrap_err().into_value(), vec![1, 2]);
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    #[test]
+    fn test_apply_with_params_throttled() {
+        use std::time::{Duration, Instant};
+        let started = Instant::now();
+        let numbers = block_on_runtime(vec![0].apply_with_params_throttled(
+            |it, n| Box::pin(async move { it.push(n) }),
+            vec![1, 2],
+            Duration::from_millis(20),
+        ));
+        assert_eq!(numbers, vec![0, 1, 2]);
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    #[test]
+    fn test_apply_blocking() {
+        let main_thread = std::thread::current().id();
+        let (numbers, thread) = block_on_runtime((vec![1], None).apply_blocking(|it| {
+            it.0.push(2);
+            it.1 = Some(std::thread::current().id());
+        }));
+        assert_eq!(numbers, vec![1, 2]);
+        assert_ne!(thread, Some(main_thread));
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    #[test]
+    #[should_panic(expected = "blocking step failed")]
+    fn test_apply_blocking_panic_case() {
+        block_on_runtime(vec![1].apply_blocking(|_| panic!("blocking step failed")));
+    }
+}
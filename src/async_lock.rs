@@ -0,0 +1,182 @@
+use core::future::Future;
+
+/// Allows you to apply any function given as a parameter to the value inside an asynchronous
+/// lock.
+///
+/// The lock is acquired asynchronously, the function is applied to the inner value, and the lock
+/// is released before the future completes, so updates of shared state can be chained.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "tokio", doc = "```")]
+#[cfg_attr(not(feature = "tokio"), doc = "```ignore")]
+/// use apply_method::*;
+/// use tokio::sync::Mutex;
+/// # futures::executor::block_on(async {
+/// let names = Mutex::new(Vec::new());
+/// names
+///     .apply_locked_async(|it| it.push("Pochi"))
+///     .await
+///     .apply_locked_async(|it| it.push("Hachi"))
+///     .await;
+/// assert_eq!(*names.lock().await, vec!["Pochi", "Hachi"]);
+/// # });
+/// ```
+///
+/// The locks of async-std are supported in the same way.
+///
+#[cfg_attr(feature = "async-std", doc = "```")]
+#[cfg_attr(not(feature = "async-std"), doc = "```ignore")]
+/// use apply_method::*;
+/// use async_std::sync::Mutex;
+/// # async_std::task::block_on(async {
+/// let names = Mutex::new(Vec::new());
+/// names.apply_locked_async(|it| it.push("Pochi")).await;
+/// assert_eq!(*names.lock().await, vec!["Pochi"]);
+/// # });
+/// ```
+///
+pub trait AsyncLockApplicable<T: ?Sized> {
+    /// Lock self, apply the function given as a parameter to the inner value, and release the
+    /// lock.
+    ///
+    /// Locks with separate read and write access are locked for writing.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "tokio", doc = "```")]
+    #[cfg_attr(not(feature = "tokio"), doc = "```ignore")]
+    /// use apply_method::*;
+    /// use tokio::sync::RwLock;
+    /// # futures::executor::block_on(async {
+    /// let count = RwLock::new(0);
+    /// count.apply_locked_async(|it| *it += 1).await;
+    /// assert_eq!(*count.read().await, 1);
+    /// # });
+    /// ```
+    ///
+    #[cfg_attr(feature = "async-std", doc = "```")]
+    #[cfg_attr(not(feature = "async-std"), doc = "```ignore")]
+    /// use apply_method::*;
+    /// use async_std::sync::RwLock;
+    /// # async_std::task::block_on(async {
+    /// let count = RwLock::new(0);
+    /// count.apply_locked_async(|it| *it += 1).await;
+    /// assert_eq!(*count.read().await, 1);
+    /// # });
+    /// ```
+    fn apply_locked_async<F, R>(&self, f: F) -> impl Future<Output = &Self> + Send
+    where
+        F: FnOnce(&mut T) -> R + Send;
+}
+
+#[cfg(feature = "tokio")]
+impl<T: ?Sized + Send> AsyncLockApplicable<T> for tokio::sync::Mutex<T> {
+    async fn apply_locked_async<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R + Send,
+    {
+        f(&mut *self.lock().await);
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: ?Sized + Send + Sync> AsyncLockApplicable<T> for tokio::sync::RwLock<T> {
+    async fn apply_locked_async<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R + Send,
+    {
+        f(&mut *self.write().await);
+        self
+    }
+}
+
+#[cfg(feature = "async-std")]
+impl<T: ?Sized + Send> AsyncLockApplicable<T> for async_std::sync::Mutex<T> {
+    async fn apply_locked_async<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R + Send,
+    {
+        f(&mut *self.lock().await);
+        self
+    }
+}
+
+#[cfg(feature = "async-std")]
+impl<T: ?Sized + Send + Sync> AsyncLockApplicable<T> for async_std::sync::RwLock<T> {
+    async fn apply_locked_async<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R + Send,
+    {
+        f(&mut *self.write().await);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "tokio")]
+    use futures::executor::block_on;
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_apply_locked_async_tokio_mutex() {
+        use std::collections::HashMap;
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = tokio::sync::Mutex::new(HashMap::new());
+        block_on(async {
+            map.apply_locked_async(|it| it.insert(1, "one"))
+                .await
+                .apply_locked_async(|it| it.insert(2, "two"))
+                .await;
+        });
+        assert_eq!(map.into_inner(), exact_map);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_apply_locked_async_tokio_rwlock() {
+        let numbers = tokio::sync::RwLock::new(vec![1]);
+        block_on(numbers.apply_locked_async(|it| it.push(2)));
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_apply_locked_async_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        fn spawnable<L: AsyncLockApplicable<Vec<i32>>>(lock: &L) {
+            assert_send(&lock.apply_locked_async(|it| it.push(2)));
+        }
+        let numbers = tokio::sync::Mutex::new(vec![1]);
+        spawnable(&numbers);
+        block_on(numbers.apply_locked_async(|it| it.push(2)));
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_apply_locked_async_async_std_mutex() {
+        let numbers = async_std::sync::Mutex::new(vec![1]);
+        async_std::task::block_on(async {
+            numbers
+                .apply_locked_async(|it| it.push(2))
+                .await
+                .apply_locked_async(|it| it.push(3))
+                .await;
+        });
+        assert_eq!(numbers.into_inner(), vec![1, 2, 3]);
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_apply_locked_async_async_std_rwlock() {
+        let numbers = async_std::sync::RwLock::new(vec![1]);
+        async_std::task::block_on(numbers.apply_locked_async(|it| it.push(2)));
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+    }
+}
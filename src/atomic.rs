@@ -0,0 +1,176 @@
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicPtr;
+use core::sync::atomic::Ordering;
+
+/// Allows you to apply any function given as a parameter to the value of an atomic type.
+///
+/// The function is applied to a copy of the current value and the result is stored with a
+/// compare-and-exchange loop. The function may be called multiple times if the value is changed
+/// by other threads in the meantime, so it should only compute the new value.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// let flags = AtomicU32::new(0b0001);
+/// flags
+///     .apply_atomic(|it| *it |= 0b0100)
+///     .apply_atomic(|it| *it &= !0b0001);
+/// assert_eq!(flags.load(Ordering::SeqCst), 0b0100);
+/// ```
+///
+pub trait AtomicApplicable<T> {
+    /// Apply the function given as a parameter to the value of self atomically, using
+    /// `Ordering::SeqCst` for both of loading and storing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// let count = AtomicUsize::new(1);
+    /// count.apply_atomic(|it| *it = (*it * 10).min(5));
+    /// assert_eq!(count.load(Ordering::SeqCst), 5);
+    /// ```
+    fn apply_atomic<F>(&self, f: F) -> &Self
+    where
+        F: FnMut(&mut T);
+
+    /// Apply the function given as a parameter to the value of self atomically with the given
+    /// orderings.
+    ///
+    /// `set_order` is used when the new value is stored, and `fetch_order` is used when the
+    /// current value is loaded, like `fetch_update` of the atomic types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// let flag = AtomicBool::new(false);
+    /// flag.apply_atomic_with_ordering(Ordering::AcqRel, Ordering::Acquire, |it| *it = !*it);
+    /// assert!(flag.load(Ordering::Acquire));
+    /// ```
+    fn apply_atomic_with_ordering<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> &Self
+    where
+        F: FnMut(&mut T);
+}
+
+macro_rules! impl_atomic_applicable {
+    ($(#[$attr:meta] $atomic:ty => $value:ty),* $(,)?) => {$(
+        #[$attr]
+        impl AtomicApplicable<$value> for $atomic {
+            fn apply_atomic<F>(&self, f: F) -> &Self
+            where
+                F: FnMut(&mut $value),
+            {
+                self.apply_atomic_with_ordering(Ordering::SeqCst, Ordering::SeqCst, f)
+            }
+
+            fn apply_atomic_with_ordering<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> &Self
+            where
+                F: FnMut(&mut $value),
+            {
+                let _ = self.fetch_update(set_order, fetch_order, |mut value| {
+                    f(&mut value);
+                    Some(value)
+                });
+                self
+            }
+        }
+    )*};
+}
+
+impl_atomic_applicable! {
+    #[cfg(target_has_atomic = "8")] core::sync::atomic::AtomicBool => bool,
+    #[cfg(target_has_atomic = "8")] core::sync::atomic::AtomicI8 => i8,
+    #[cfg(target_has_atomic = "8")] core::sync::atomic::AtomicU8 => u8,
+    #[cfg(target_has_atomic = "16")] core::sync::atomic::AtomicI16 => i16,
+    #[cfg(target_has_atomic = "16")] core::sync::atomic::AtomicU16 => u16,
+    #[cfg(target_has_atomic = "32")] core::sync::atomic::AtomicI32 => i32,
+    #[cfg(target_has_atomic = "32")] core::sync::atomic::AtomicU32 => u32,
+    #[cfg(target_has_atomic = "64")] core::sync::atomic::AtomicI64 => i64,
+    #[cfg(target_has_atomic = "64")] core::sync::atomic::AtomicU64 => u64,
+    #[cfg(target_has_atomic = "ptr")] core::sync::atomic::AtomicIsize => isize,
+    #[cfg(target_has_atomic = "ptr")] core::sync::atomic::AtomicUsize => usize,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> AtomicApplicable<*mut T> for AtomicPtr<T> {
+    fn apply_atomic<F>(&self, f: F) -> &Self
+    where
+        F: FnMut(&mut *mut T),
+    {
+        self.apply_atomic_with_ordering(Ordering::SeqCst, Ordering::SeqCst, f)
+    }
+
+    fn apply_atomic_with_ordering<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> &Self
+    where
+        F: FnMut(&mut *mut T),
+    {
+        let _ = self.fetch_update(set_order, fetch_order, |mut value| {
+            f(&mut value);
+            Some(value)
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicI64, AtomicPtr, AtomicUsize};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_apply_atomic() {
+        let number = AtomicI64::new(1);
+        number
+            .apply_atomic(|it| *it *= 3)
+            .apply_atomic(|it| *it -= 5);
+        assert_eq!(number.load(Ordering::SeqCst), -2);
+    }
+
+    #[test]
+    fn test_apply_atomic_concurrently() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let count = Arc::clone(&count);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        count.apply_atomic(|it| *it += 1);
+                    }
+                })
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(count.load(Ordering::SeqCst), 4000);
+    }
+
+    #[test]
+    fn test_apply_atomic_ptr() {
+        let mut first = 1;
+        let mut second = 2;
+        let second_ptr: *mut i32 = &mut second;
+        let ptr = AtomicPtr::new(&mut first);
+        ptr.apply_atomic_with_ordering(Ordering::Release, Ordering::Acquire, |it| *it = second_ptr);
+        assert_eq!(ptr.load(Ordering::Acquire), second_ptr);
+    }
+}
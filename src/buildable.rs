@@ -0,0 +1,51 @@
+/// Allows you to construct any `Default` type and apply a function to it in one step.
+///
+/// This gives every `Default` type a fluent constructor without writing a builder.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Dog {
+///     name: String,
+///     age: u8,
+/// }
+/// let dog = Dog::build_with(|it| {
+///     it.name = "Pochi".to_string();
+///     it.age = 3;
+/// });
+/// assert_eq!(dog, Dog { name: "Pochi".to_string(), age: 3 });
+/// ```
+pub trait Buildable: Default {
+    /// Create the default value, apply the function given as a parameter to it, and return it.
+    fn build_with<F, R>(f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R;
+}
+
+impl<T: Default> Buildable for T {
+    fn build_with<F, R>(f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let mut receiver = Self::default();
+        f(&mut receiver);
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_build_with() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::build_with(|it| it.insert(1, "one"));
+        assert_eq!(map, exact_map);
+        assert_eq!(Vec::<i32>::build_with(|_| ()), vec![]);
+    }
+}
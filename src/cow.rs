@@ -0,0 +1,108 @@
+use alloc::borrow::{Cow, ToOwned};
+
+/// Allows you to apply any function given as a parameter to the owned value of a `Cow`.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::borrow::Cow;
+/// fn normalize(name: &str) -> Cow<'_, str> {
+///     Cow::Borrowed(name).apply_cow_when(|it| it.contains(' '), |it| *it = it.replace(' ', "_"))
+/// }
+/// assert!(matches!(normalize("Pochi"), Cow::Borrowed("Pochi")));
+/// assert_eq!(normalize("Pochi Hachi"), "Pochi_Hachi");
+/// ```
+///
+pub trait CowApplicable<B: ?Sized + ToOwned> {
+    /// Apply the function given as a parameter to the owned value of self, and return self.
+    ///
+    /// A borrowed value is always promoted to an owned one by `to_mut` before the function is
+    /// applied, so the result is always `Cow::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::borrow::Cow;
+    /// let name = Cow::Borrowed("Pochi").apply_cow(|it| it.push('!'));
+    /// assert!(matches!(name, Cow::Owned(_)));
+    /// assert_eq!(name, "Pochi!");
+    /// ```
+    fn apply_cow<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut B::Owned) -> R;
+
+    /// Apply the function given as a parameter to the owned value of self only if the predicate
+    /// returns true for the current value, and return self.
+    ///
+    /// A borrowed value stays borrowed when the predicate returns false, so no clone happens if
+    /// there is nothing to change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::borrow::Cow;
+    /// let numbers = [1, 2, 3];
+    /// let numbers = Cow::Borrowed(&numbers[..]).apply_cow_when(|it| it.contains(&0), |it| it.clear());
+    /// assert!(matches!(numbers, Cow::Borrowed(_)));
+    /// ```
+    fn apply_cow_when<P, F, R>(self, predicate: P, f: F) -> Self
+    where
+        P: FnOnce(&B) -> bool,
+        F: FnOnce(&mut B::Owned) -> R;
+}
+
+impl<'a, B: ?Sized + ToOwned> CowApplicable<B> for Cow<'a, B> {
+    fn apply_cow<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut B::Owned) -> R,
+    {
+        let mut receiver = self;
+        f(receiver.to_mut());
+        receiver
+    }
+
+    fn apply_cow_when<P, F, R>(self, predicate: P, f: F) -> Self
+    where
+        P: FnOnce(&B) -> bool,
+        F: FnOnce(&mut B::Owned) -> R,
+    {
+        if predicate(&self) {
+            self.apply_cow(f)
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply_cow() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+        let map = Cow::Borrowed(&map).apply_cow(|it| it.insert(2, "two"));
+        assert!(matches!(map, Cow::Owned(_)));
+        assert_eq!(*map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_cow_when() {
+        let numbers = vec![1, 2];
+        let unchanged =
+            Cow::Borrowed(&numbers[..]).apply_cow_when(|it| it.len() < 2, |it| it.push(3));
+        assert!(matches!(unchanged, Cow::Borrowed(_)));
+        let changed =
+            Cow::Borrowed(&numbers[..]).apply_cow_when(|it| it.len() < 3, |it| it.push(3));
+        assert_eq!(*changed, [1, 2, 3]);
+        assert_eq!(numbers, vec![1, 2]);
+    }
+}
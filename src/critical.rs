@@ -0,0 +1,78 @@
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// Allows you to apply any function given as a parameter to the value shared through a
+/// `critical_section::Mutex`.
+///
+/// This is the counterpart of the lock extensions for `no_std` targets: the function is applied
+/// inside a critical section, so shared statics of embedded programs can be updated with one line.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use core::cell::RefCell;
+/// use critical_section::Mutex;
+/// static TICKS: Mutex<RefCell<u32>> = Mutex::new(RefCell::new(0));
+/// TICKS
+///     .apply_critical(|it| *it += 1)
+///     .apply_critical(|it| *it += 1);
+/// assert_eq!(critical_section::with(|cs| *TICKS.borrow_ref(cs)), 2);
+/// ```
+///
+pub trait CriticalSectionApplicable<T> {
+    /// Enter a critical section, apply the function given as a parameter to the inner value, and
+    /// leave the critical section.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner value is already borrowed, e.g. when this is called from the function
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use core::cell::RefCell;
+    /// use critical_section::Mutex;
+    /// let names = Mutex::new(RefCell::new(Vec::new()));
+    /// names.apply_critical(|it| it.push("Pochi"));
+    /// assert_eq!(names.into_inner().into_inner(), vec!["Pochi"]);
+    /// ```
+    fn apply_critical<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T> CriticalSectionApplicable<T> for Mutex<RefCell<T>> {
+    fn apply_critical<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        critical_section::with(|cs| {
+            f(&mut *self.borrow_ref_mut(cs));
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_critical() {
+        let counter = Mutex::new(RefCell::new(0));
+        counter
+            .apply_critical(|it| *it += 1)
+            .apply_critical(|it| *it *= 3);
+        assert_eq!(counter.into_inner().into_inner(), 3);
+    }
+
+    #[test]
+    fn test_apply_critical_static() {
+        static NUMBERS: Mutex<RefCell<[i32; 2]>> = Mutex::new(RefCell::new([1, 0]));
+        NUMBERS.apply_critical(|it| it[1] = 2);
+        assert_eq!(critical_section::with(|cs| *NUMBERS.borrow_ref(cs)), [1, 2]);
+    }
+}
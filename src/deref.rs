@@ -0,0 +1,80 @@
+use core::ops::DerefMut;
+
+/// Allows you to apply any function given as a parameter to the value behind a smart pointer.
+///
+/// `Applicable::apply` gives the function the pointer itself, e.g. `&mut Box<T>`. The methods of
+/// this trait give it the target instead, so `Box`, `MutexGuard`, `RefMut` and the other
+/// `DerefMut` wrappers can be chained without dereferencing twice.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::cell::RefCell;
+/// let names = RefCell::new(Vec::new());
+/// names
+///     .borrow_mut()
+///     .apply_inner(|it| it.push("Pochi"))
+///     .apply_inner(|it| it.push("Hachi"));
+/// assert_eq!(*names.borrow(), vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub trait ApplicableDeref: DerefMut + Sized {
+    /// Apply the function given as a parameter to the target of self, and return self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let name = Box::new(String::from("Pochi")).apply_inner(|it| it.push('!'));
+    /// assert_eq!(*name, "Pochi!");
+    /// ```
+    fn apply_inner<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self::Target) -> R;
+}
+
+impl<T: DerefMut> ApplicableDeref for T {
+    fn apply_inner<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self::Target) -> R,
+    {
+        let mut receiver = self;
+        f(&mut *receiver);
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_apply_inner_box() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = Box::new(HashMap::new())
+            .apply_inner(|it| it.insert(1, "one"))
+            .apply_inner(|it| it.insert(2, "two"));
+        assert_eq!(*map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_inner_guard() {
+        let numbers = Mutex::new(vec![1]);
+        let guard = numbers.lock().unwrap().apply_inner(|it| it.push(2));
+        assert_eq!(*guard, vec![1, 2]);
+        drop(guard);
+        assert_eq!(numbers.into_inner().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_inner_unsized_target() {
+        let numbers: Box<[i32]> = Box::new([3, 1, 2]);
+        let numbers = numbers.apply_inner(|it| it.sort());
+        assert_eq!(*numbers, [1, 2, 3]);
+    }
+}
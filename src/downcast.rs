@@ -0,0 +1,93 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// Allows you to apply any function given as a parameter to the concrete value of a boxed
+/// `dyn Any`.
+///
+/// This is useful to mutate the entries of heterogeneous registries with the same chain.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::any::Any;
+/// let registry: Vec<Box<dyn Any>> = vec![Box::new(1), Box::new(String::from("Pochi"))];
+/// let registry: Vec<Box<dyn Any>> = registry
+///     .into_iter()
+///     .map(|it| it.apply_downcast::<String, _, _>(|it| it.push('!')).unwrap_or_else(|it| it))
+///     .collect();
+/// assert_eq!(registry[1].downcast_ref::<String>().unwrap(), "Pochi!");
+/// ```
+///
+pub trait DowncastApplicable: Sized {
+    /// Apply the function given as a parameter to the value of self if it is a `T`.
+    ///
+    /// Return `Ok(self)` if the function was applied, and `Err(self)` without applying it if the
+    /// value is of another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::any::Any;
+    /// let value: Box<dyn Any> = Box::new(1);
+    /// let value = value.apply_downcast::<i32, _, _>(|it| *it += 1).unwrap();
+    /// assert_eq!(value.downcast_ref::<i32>(), Some(&2));
+    /// assert!(value.apply_downcast::<String, _, _>(|it| it.clear()).is_err());
+    /// ```
+    fn apply_downcast<T, F, R>(self, f: F) -> Result<Self, Self>
+    where
+        T: Any,
+        F: FnOnce(&mut T) -> R;
+}
+
+macro_rules! impl_downcast_applicable {
+    ($($bounds:tt)*) => {
+        impl DowncastApplicable for Box<dyn Any $($bounds)*> {
+            fn apply_downcast<T, F, R>(self, f: F) -> Result<Self, Self>
+            where
+                T: Any,
+                F: FnOnce(&mut T) -> R,
+            {
+                let mut receiver = self;
+                match receiver.downcast_mut::<T>() {
+                    Some(value) => {
+                        f(value);
+                        Ok(receiver)
+                    }
+                    None => Err(receiver),
+                }
+            }
+        }
+    };
+}
+
+impl_downcast_applicable!();
+impl_downcast_applicable!(+ Send);
+impl_downcast_applicable!(+ Send + Sync);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply_downcast() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map: Box<dyn Any> = Box::new(HashMap::<i32, &str>::new());
+        let map = map
+            .apply_downcast::<HashMap<i32, &str>, _, _>(|it| it.insert(1, "one"))
+            .unwrap();
+        assert_eq!(map.downcast_ref::<HashMap<i32, &str>>(), Some(&exact_map));
+    }
+
+    #[test]
+    fn test_apply_downcast_mismatched_case() {
+        let numbers: Box<dyn Any + Send + Sync> = Box::new(vec![1]);
+        let numbers = numbers
+            .apply_downcast::<Vec<u8>, _, _>(|it| it.push(2))
+            .unwrap_err();
+        assert_eq!(numbers.downcast_ref::<Vec<i32>>(), Some(&vec![1]));
+    }
+}
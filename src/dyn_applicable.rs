@@ -0,0 +1,120 @@
+use alloc::boxed::Box;
+use core::error::Error;
+
+/// The boxed error returned by the fallible function given to [`DynApplicable::try_apply_boxed`].
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// The boxed function which is not necessarily `Send`, accepted by
+/// [`DynApplicable::apply_boxed`] and [`Applicable::apply_batch`](crate::Applicable::apply_batch).
+pub type LocalApplyFn<'a, T> = Box<dyn FnOnce(&mut T) + 'a>;
+
+/// The boxed fallible function accepted by [`DynApplicable::try_apply_boxed`].
+pub type TryApplyFn<'a, T> = Box<dyn FnOnce(&mut T) -> Result<(), BoxError> + 'a>;
+
+/// The object-safe companion of [`Applicable`](crate::Applicable), which accepts type-erased
+/// functions.
+///
+/// The generic closures of `Applicable` prevent it from being used as a trait object. This trait
+/// takes boxed functions instead, so it can be used as `dyn DynApplicable<T>` and the functions
+/// can be stored until they are applied, e.g. by plugins.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let plugins: Vec<Box<dyn FnOnce(&mut Vec<&str>)>> = vec![
+///     Box::new(|it| it.push("Pochi")),
+///     Box::new(|it| it.push("Hachi")),
+/// ];
+/// let mut names = Vec::new();
+/// let target: &mut dyn DynApplicable<Vec<&str>> = &mut names;
+/// for plugin in plugins {
+///     target.apply_boxed(plugin);
+/// }
+/// assert_eq!(names, vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub trait DynApplicable<T: ?Sized> {
+    /// Apply the boxed function given as a parameter to self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let mut path = PathBuf::from("src");
+    /// path.apply_boxed(Box::new(|it: &mut PathBuf| it.push("lib.rs")));
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_boxed(&mut self, f: LocalApplyFn<'_, T>);
+
+    /// Apply the boxed fallible function given as a parameter to self, and return the error if
+    /// it failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let mut count = 1;
+    /// let result = count.try_apply_boxed(Box::new(|it: &mut i32| {
+    ///     *it = "2".parse::<i32>()?;
+    ///     Ok(())
+    /// }));
+    /// assert!(result.is_ok());
+    /// assert_eq!(count, 2);
+    /// ```
+    fn try_apply_boxed(&mut self, f: TryApplyFn<'_, T>) -> Result<(), BoxError>;
+}
+
+impl<T: ?Sized> DynApplicable<T> for T {
+    fn apply_boxed(&mut self, f: LocalApplyFn<'_, T>) {
+        f(self)
+    }
+
+    fn try_apply_boxed(&mut self, f: TryApplyFn<'_, T>) -> Result<(), BoxError> {
+        f(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply_boxed() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let operations: Vec<crate::ApplyFn<HashMap<_, _>>> = vec![
+            Box::new(|it| {
+                it.insert(1, "one");
+            }),
+            Box::new(|it| {
+                it.insert(2, "two");
+            }),
+        ];
+        let mut map = HashMap::new();
+        let target: &mut dyn DynApplicable<HashMap<_, _>> = &mut map;
+        for operation in operations {
+            target.apply_boxed(operation);
+        }
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_try_apply_boxed() {
+        let mut numbers = vec![1];
+        let result = numbers.try_apply_boxed(Box::new(|it: &mut Vec<i32>| {
+            it.push("2".parse::<i32>()?);
+            Ok(())
+        }));
+        assert!(result.is_ok());
+        let result = numbers.try_apply_boxed(Box::new(|it: &mut Vec<i32>| {
+            it.push("three".parse::<i32>()?);
+            Ok(())
+        }));
+        assert!(result.is_err());
+        assert_eq!(numbers, vec![1, 2]);
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::error::Error;
+use core::fmt;
+
+/// The error returned when applying the function to one of multiple parameters failed.
+///
+/// It keeps the position of the parameter which caused the failure together with the original
+/// error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyError<E> {
+    /// The zero-based position of the failed parameter.
+    pub index: usize,
+    /// The error returned by the applied function.
+    pub source: E,
+}
+
+impl<E> ApplyError<E> {
+    /// Create a new error for the parameter at the given position.
+    pub fn new(index: usize, source: E) -> Self {
+        Self { index, source }
+    }
+
+    /// Unwrap the original error.
+    pub fn into_source(self) -> E {
+        self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ApplyError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to apply parameter {}: {}",
+            self.index, self.source
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for ApplyError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The error returned when a step of the chain failed, with the message identifying the step.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextError<E> {
+    /// The message identifying the failed step.
+    pub context: String,
+    /// The error returned by the applied function.
+    pub source: E,
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ContextError<E> {
+    /// Create a new error with the given message.
+    pub fn new<C: fmt::Display>(context: C, source: E) -> Self {
+        Self {
+            context: context.to_string(),
+            source,
+        }
+    }
+
+    /// Unwrap the original error.
+    pub fn into_source(self) -> E {
+        self.source
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display> fmt::Display for ContextError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.source)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Error + 'static> Error for ContextError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The error returned when a transactional step failed and the value was rolled back.
+///
+/// It gives back the value in the state before the failed step together with the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollbackError<T, E> {
+    /// The value restored to the state before the failed step.
+    pub value: T,
+    /// The error returned by the applied function.
+    pub source: E,
+}
+
+impl<T, E> RollbackError<T, E> {
+    /// Create a new error with the restored value.
+    pub fn new(value: T, source: E) -> Self {
+        Self { value, source }
+    }
+
+    /// Unwrap the restored value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for RollbackError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rolled back after failure: {}", self.source)
+    }
+}
+
+impl<T: fmt::Debug, E: Error + 'static> Error for RollbackError<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The error returned when an asynchronous step did not complete before its deadline.
+///
+/// It gives back the value in the state left by the step when the deadline expired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elapsed<T> {
+    /// The value in the state when the step was cancelled.
+    pub value: T,
+}
+
+impl<T> Elapsed<T> {
+    /// Create a new error with the given value.
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Unwrap the value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for Elapsed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline has elapsed")
+    }
+}
+
+impl<T: fmt::Debug> Error for Elapsed<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_error_display() {
+        let source = "x".parse::<i32>().unwrap_err();
+        let error = ApplyError::new(2, source.clone());
+        assert_eq!(
+            error.to_string(),
+            format!("failed to apply parameter 2: {}", source)
+        );
+    }
+
+    #[test]
+    fn test_apply_error_source() {
+        let error = ApplyError::new(0, "x".parse::<i32>().unwrap_err());
+        assert!(Error::source(&error).is_some());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_context_error_display() {
+        let source = "x".parse::<i32>().unwrap_err();
+        let error = ContextError::new("parse port", source.clone());
+        assert_eq!(error.to_string(), format!("parse port: {}", source));
+        assert!(Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_rollback_error_display() {
+        let source = "x".parse::<i32>().unwrap_err();
+        let error = RollbackError::new(vec![1], source.clone());
+        assert_eq!(
+            error.to_string(),
+            format!("rolled back after failure: {}", source)
+        );
+        assert_eq!(error.into_value(), vec![1]);
+    }
+
+    #[test]
+    fn test_elapsed_display() {
+        let error = Elapsed::new(vec![1]);
+        assert_eq!(error.to_string(), "deadline has elapsed");
+        assert_eq!(error.into_value(), vec![1]);
+    }
+}
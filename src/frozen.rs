@@ -0,0 +1,52 @@
+use core::ops::Deref;
+
+/// The value sealed by [`Applicable::seal`](crate::Applicable::seal), which can't be mutated
+/// anymore.
+///
+/// Only shared access through `Deref` is exposed. `Frozen` itself still has the methods of
+/// `Applicable` like any other type, but the functions only receive `&mut Frozen<T>`, which
+/// gives no way to reach the inner value mutably.
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use apply_method::*;
+/// let names = vec!["Pochi"].seal();
+/// names.apply(|it| it.push("Hachi"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frozen<T>(T);
+
+impl<T> Frozen<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for Frozen<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Frozen<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen() {
+        let numbers = Frozen::new(vec![1, 2]);
+        assert_eq!(numbers.len(), 2);
+        assert_eq!(numbers.as_ref(), &vec![1, 2]);
+        assert!(Frozen::new(1) < Frozen::new(2));
+        assert_eq!(numbers.clone(), numbers);
+    }
+}
@@ -0,0 +1,81 @@
+use core::future::Future;
+
+/// Allows you to apply any function given as a parameter to the output of the future.
+///
+/// This keeps the chain fluent even if the object itself is created by an asynchronous
+/// constructor.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// # futures::executor::block_on(async {
+/// async fn load_names() -> Vec<&'static str> {
+///     vec!["Pochi"]
+/// }
+/// let names = load_names()
+///     .then_apply(|it| it.push("Hachi"))
+///     .then_apply(|it| it.sort())
+///     .await;
+/// assert_eq!(names, vec!["Hachi", "Pochi"]);
+/// # });
+/// ```
+///
+pub trait FutureApplicable: Future + Sized {
+    /// Return the future which applies the function given as a parameter to the output of self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let path = std::future::ready(PathBuf::from("src"))
+    ///     .then_apply(|it| it.push("lib.rs"))
+    ///     .await;
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// # });
+    /// ```
+    fn then_apply<F, R>(self, f: F) -> impl Future<Output = Self::Output>
+    where
+        F: FnOnce(&mut Self::Output) -> R;
+}
+
+impl<T: Future> FutureApplicable for T {
+    async fn then_apply<F, R>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(&mut Self::Output) -> R,
+    {
+        let mut output = self.await;
+        f(&mut output);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_then_apply() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = block_on(
+            async { HashMap::new() }
+                .then_apply(|it| it.insert(1, "one"))
+                .then_apply(|it| it.insert(2, "two")),
+        );
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_then_apply_is_lazy() {
+        let mut called = false;
+        let future = std::future::ready(1).then_apply(|_| called = true);
+        drop(future);
+        assert!(!called);
+    }
+}
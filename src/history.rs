@@ -0,0 +1,143 @@
+use alloc::collections::VecDeque;
+use core::ops::Deref;
+
+/// The wrapper which keeps the previous states of the value every time a function is applied.
+///
+/// Up to `limit` previous states are kept, and the oldest one is discarded when the limit is
+/// exceeded. This is useful for debugging long chains and for reverting to an earlier state.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let mut names = History::new(vec!["Pochi"], 2)
+///     .apply(|it| it.push("Hachi"))
+///     .apply(|it| it.push("Shiro"));
+/// assert_eq!(names.previous(1), Some(&vec!["Pochi", "Hachi"]));
+/// assert_eq!(names.previous(2), Some(&vec!["Pochi"]));
+/// names.apply_mut(|it| it.clear());
+/// assert_eq!(names.previous(3), None);
+/// assert!(names.revert(1));
+/// assert_eq!(*names, vec!["Pochi", "Hachi", "Shiro"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    value: T,
+    previous: VecDeque<T>,
+    limit: usize,
+}
+
+impl<T: Clone> History<T> {
+    /// Create a new wrapper which keeps up to `limit` previous states.
+    pub fn new(value: T, limit: usize) -> Self {
+        Self {
+            value,
+            previous: VecDeque::with_capacity(limit),
+            limit,
+        }
+    }
+
+    /// Record the current state, apply the function given as a parameter to the value, and
+    /// return self.
+    pub fn apply<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut receiver = self;
+        receiver.apply_mut(f);
+        receiver
+    }
+
+    /// Record the current state, apply the function given as a parameter to the value in place,
+    /// and return the mutable reference to self.
+    ///
+    /// This deliberately shadows [`ApplicableMut::apply_mut`](crate::ApplicableMut::apply_mut),
+    /// which would pass the wrapper itself to the function, so that calling `apply_mut` on the
+    /// wrapper always records the state.
+    pub fn apply_mut<F, R>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        if self.limit > 0 {
+            if self.previous.len() == self.limit {
+                self.previous.pop_back();
+            }
+            self.previous.push_front(self.value.clone());
+        }
+        f(&mut self.value);
+        self
+    }
+
+    /// Return the state before the last `n` applies, e.g. `previous(1)` is the state before the
+    /// last apply, or `None` if it is not kept.
+    pub fn previous(&self, n: usize) -> Option<&T> {
+        match n {
+            0 => Some(&self.value),
+            n => self.previous.get(n - 1),
+        }
+    }
+
+    /// Restore the state before the last `n` applies, and discard the newer states.
+    ///
+    /// Return false without changing anything if the state is not kept.
+    pub fn revert(&mut self, n: usize) -> bool {
+        if n == 0 {
+            return true;
+        }
+        if n > self.previous.len() {
+            return false;
+        }
+        self.previous.drain(..n - 1);
+        self.value = self.previous.pop_front().expect("the state is kept");
+        true
+    }
+
+    /// Unwrap the current value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for History<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_history() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = History::new(HashMap::new(), 3)
+            .apply(|it| it.insert(1, "one"))
+            .apply(|it| it.insert(2, "two"));
+        assert_eq!(map.previous(1), Some(&exact_map));
+        assert_eq!(map.previous(2), Some(&HashMap::new()));
+        assert_eq!(map.previous(3), None);
+    }
+
+    #[test]
+    fn test_history_limit() {
+        let mut numbers = History::new(vec![1], 1);
+        numbers
+            .apply_mut(|it| it.push(2))
+            .apply_mut(|it| it.push(3));
+        assert_eq!(numbers.previous(1), Some(&vec![1, 2]));
+        assert_eq!(numbers.previous(2), None);
+        assert!(!numbers.revert(2));
+        assert!(numbers.revert(1));
+        assert_eq!(numbers.previous(1), None);
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+
+        let numbers = History::new(vec![1], 0).apply(|it| it.push(2));
+        assert_eq!(numbers.previous(1), None);
+        assert_eq!(*numbers, vec![1, 2]);
+    }
+}
@@ -0,0 +1,163 @@
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+// Makes the lifetime invariant, so the proof of one slot cannot be used for another slot.
+type Brand<'a> = PhantomData<fn(&'a ()) -> &'a ()>;
+
+/// The uninitialized slot given to the function of [`InitApplicable::apply_init`].
+///
+/// The function has to return the [`Init`] proof of this slot, which can only be created by
+/// writing the whole value.
+pub struct Uninit<'a, T> {
+    slot: &'a mut MaybeUninit<T>,
+    brand: Brand<'a>,
+}
+
+/// The proof that the [`Uninit`] slot with the same lifetime has been initialized.
+pub struct Init<'a, T> {
+    slot: &'a mut MaybeUninit<T>,
+    brand: Brand<'a>,
+}
+
+impl<'a, T> Uninit<'a, T> {
+    /// Write the value to the slot, and return the proof of the initialization.
+    pub fn write(self, value: T) -> Init<'a, T> {
+        self.slot.write(value);
+        Init {
+            slot: self.slot,
+            brand: self.brand,
+        }
+    }
+
+    /// Return the raw pointer to the slot, which can be used to initialize the fields one by one.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.slot.as_mut_ptr()
+    }
+
+    /// Return the proof of the initialization without writing the value.
+    ///
+    /// # Safety
+    ///
+    /// The whole value must have been initialized through [`Uninit::as_mut_ptr`].
+    pub unsafe fn assume_init(self) -> Init<'a, T> {
+        Init {
+            slot: self.slot,
+            brand: self.brand,
+        }
+    }
+}
+
+/// Allows you to initialize a `MaybeUninit` by applying a function, without `unsafe`.
+///
+/// The function receives the [`Uninit`] slot and has to return its [`Init`] proof, so
+/// `assume_init` is only called after the value has surely been written.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::mem::MaybeUninit;
+/// let mut buffer = MaybeUninit::<[u8; 5]>::uninit();
+/// let bytes = buffer.apply_init_mut(|it| it.write(*b"P_chi")).apply(|it| it[1] = b'o');
+/// assert_eq!(bytes, b"Pochi");
+/// ```
+///
+/// The proof of another slot is rejected at compile time:
+///
+/// ```compile_fail
+/// use apply_method::*;
+/// use std::mem::MaybeUninit;
+/// let mut other = MaybeUninit::<i32>::uninit();
+/// let number = MaybeUninit::<i32>::uninit().apply_init(|first| {
+///     let mut proof = None;
+///     other.apply_init_mut(|second| {
+///         proof = Some(second.write(1));
+///         first.write(2)
+///     });
+///     proof.unwrap()
+/// });
+/// ```
+///
+pub trait InitApplicable<T> {
+    /// Initialize self by applying the function given as a parameter, and return the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::mem::MaybeUninit;
+    /// let name = MaybeUninit::uninit().apply_init(|it| it.write(String::from("Pochi")));
+    /// assert_eq!(name, "Pochi");
+    /// ```
+    fn apply_init<F>(self, f: F) -> T
+    where
+        F: for<'a> FnOnce(Uninit<'a, T>) -> Init<'a, T>;
+
+    /// Initialize self in place by applying the function given as a parameter, and return the
+    /// mutable reference to the value.
+    ///
+    /// If self has already been initialized, the previous value is overwritten without being
+    /// dropped.
+    fn apply_init_mut<F>(&mut self, f: F) -> &mut T
+    where
+        F: for<'a> FnOnce(Uninit<'a, T>) -> Init<'a, T>;
+}
+
+impl<T> InitApplicable<T> for MaybeUninit<T> {
+    fn apply_init<F>(self, f: F) -> T
+    where
+        F: for<'a> FnOnce(Uninit<'a, T>) -> Init<'a, T>,
+    {
+        let mut receiver = self;
+        receiver.apply_init_mut(f);
+        // Safety: `apply_init_mut` has initialized the value.
+        unsafe { receiver.assume_init() }
+    }
+
+    fn apply_init_mut<F>(&mut self, f: F) -> &mut T
+    where
+        F: for<'a> FnOnce(Uninit<'a, T>) -> Init<'a, T>,
+    {
+        let init = f(Uninit {
+            slot: self,
+            brand: PhantomData,
+        });
+        // Safety: `Init` can only be created after the whole value of this slot is written.
+        unsafe { init.slot.assume_init_mut() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::ptr::addr_of_mut;
+
+    #[test]
+    fn test_apply_init() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = MaybeUninit::uninit().apply_init(|it| it.write(exact_map.clone()));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_init_mut_fields() {
+        struct Dog {
+            name: &'static str,
+            age: u32,
+        }
+        let mut slot = MaybeUninit::<Dog>::uninit();
+        let dog = slot.apply_init_mut(|mut it| {
+            let ptr = it.as_mut_ptr();
+            unsafe {
+                addr_of_mut!((*ptr).name).write("Pochi");
+                addr_of_mut!((*ptr).age).write(3);
+                it.assume_init()
+            }
+        });
+        dog.age += 1;
+        assert_eq!(dog.name, "Pochi");
+        assert_eq!(dog.age, 4);
+    }
+}
@@ -0,0 +1,156 @@
+use core::cell::{BorrowMutError, Cell, RefCell};
+use core::convert::Infallible;
+
+/// Allows you to apply any function given as a parameter to the value inside an interior
+/// mutability container.
+///
+/// The container is borrowed only while the function runs, so updates through a shared
+/// reference can be chained without explicit `borrow_mut` or `get`/`set` calls.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::cell::{Cell, RefCell};
+/// let names = RefCell::new(Vec::new());
+/// names
+///     .apply_interior(|it| it.push("Pochi"))
+///     .apply_interior(|it| it.push("Hachi"));
+/// assert_eq!(*names.borrow(), vec!["Pochi", "Hachi"]);
+///
+/// let count = Cell::new(0);
+/// count.apply_interior(|it| *it += 1).apply_interior(|it| *it *= 10);
+/// assert_eq!(count.get(), 10);
+/// ```
+///
+pub trait InteriorApplicable<T: ?Sized> {
+    /// The error returned when the value cannot be borrowed mutably.
+    type Error;
+
+    /// Borrow the inner value mutably, and apply the function given as a parameter to it.
+    ///
+    /// A `Cell` copies the value out, applies the function to the copy, and sets it back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `RefCell` is already borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::cell::RefCell;
+    /// let count = RefCell::new(1);
+    /// count.apply_interior(|it| *it += 1);
+    /// assert_eq!(count.into_inner(), 2);
+    /// ```
+    fn apply_interior<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R;
+
+    /// Try to borrow the inner value mutably, and apply the function given as a parameter to it.
+    ///
+    /// If the value is already borrowed, the function is not applied and the error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::cell::RefCell;
+    /// let count = RefCell::new(1);
+    /// let borrowed = count.borrow();
+    /// assert!(count.try_apply_interior(|it| *it += 1).is_err());
+    /// drop(borrowed);
+    /// assert!(count.try_apply_interior(|it| *it += 1).is_ok());
+    /// assert_eq!(count.into_inner(), 2);
+    /// ```
+    fn try_apply_interior<F, R>(&self, f: F) -> Result<&Self, Self::Error>
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T: ?Sized> InteriorApplicable<T> for RefCell<T> {
+    type Error = BorrowMutError;
+
+    fn apply_interior<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *self.borrow_mut());
+        self
+    }
+
+    fn try_apply_interior<F, R>(&self, f: F) -> Result<&Self, Self::Error>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *self.try_borrow_mut()?);
+        Ok(self)
+    }
+}
+
+impl<T: Copy> InteriorApplicable<T> for Cell<T> {
+    type Error = Infallible;
+
+    fn apply_interior<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut value = self.get();
+        f(&mut value);
+        self.set(value);
+        self
+    }
+
+    fn try_apply_interior<F, R>(&self, f: F) -> Result<&Self, Self::Error>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        Ok(self.apply_interior(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply_interior_ref_cell() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = RefCell::new(HashMap::new());
+        map.apply_interior(|it| it.insert(1, "one"))
+            .apply_interior(|it| it.insert(2, "two"));
+        assert_eq!(map.into_inner(), exact_map);
+    }
+
+    #[test]
+    fn test_try_apply_interior_borrowed_case() {
+        let numbers = RefCell::new(vec![1]);
+        let result = numbers.try_apply_interior(|it| {
+            it.push(2);
+            assert!(numbers.try_apply_interior(|it| it.push(3)).is_err());
+        });
+        assert!(result.is_ok());
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_interior_cell() {
+        let point = Cell::new((1, 2));
+        point
+            .apply_interior(|it| it.0 += 10)
+            .try_apply_interior(|it| it.1 += 20)
+            .unwrap();
+        assert_eq!(point.get(), (11, 22));
+    }
+
+    #[test]
+    fn test_apply_interior_unsized() {
+        let numbers: &RefCell<[i32]> = &RefCell::new([3, 1, 2]);
+        numbers.apply_interior(|it| it.sort());
+        assert_eq!(*numbers.borrow(), [1, 2, 3]);
+    }
+}
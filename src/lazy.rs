@@ -0,0 +1,223 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::{Cell, OnceCell};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+const POISONED: &str = "a pending function of LazyApplied has panicked";
+const REENTERED: &str = "a pending function of LazyApplied has accessed the value being applied";
+
+type Function<'a, T> = Box<dyn FnOnce(&mut T) + 'a>;
+
+struct Pending<'a, T> {
+    base: T,
+    functions: Vec<Function<'a, T>>,
+}
+
+struct Forcing<'a>(&'a Cell<bool>);
+
+impl Drop for Forcing<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+impl<T> Pending<'_, T> {
+    fn run(self) -> T {
+        let mut value = self.base;
+        for f in self.functions {
+            f(&mut value);
+        }
+        value
+    }
+}
+
+/// The value whose functions are applied lazily on first access.
+///
+/// The functions are queued by [`LazyApplied::apply_lazy`], and applied in order when the value
+/// is accessed for the first time through `Deref`, `DerefMut` or [`LazyApplied::into_inner`], so
+/// an expensive configuration costs nothing unless the value is actually used. The pending
+/// functions may borrow from their environment for the lifetime `'a`.
+///
+/// # Panics
+///
+/// Accessing the value panics if one of the pending functions panicked before, or if a pending
+/// function accesses the same value again while it is being applied.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// let count = Rc::new(Cell::new(0));
+/// let counter = Rc::clone(&count);
+/// let names = LazyApplied::new(Vec::new())
+///     .apply_lazy(move |it| {
+///         counter.set(counter.get() + 1);
+///         it.push("Pochi");
+///     })
+///     .apply_lazy(|it| it.push("Hachi"));
+/// assert_eq!(count.get(), 0);
+/// assert_eq!(*names, vec!["Pochi", "Hachi"]);
+/// assert_eq!(names.len(), 2);
+/// assert_eq!(count.get(), 1);
+/// ```
+pub struct LazyApplied<'a, T> {
+    value: OnceCell<T>,
+    pending: Cell<Option<Pending<'a, T>>>,
+    forcing: Cell<bool>,
+}
+
+impl<'a, T> LazyApplied<'a, T> {
+    /// Create a new wrapper with the base value and no pending function.
+    pub fn new(value: T) -> Self {
+        Self {
+            value: OnceCell::new(),
+            pending: Cell::new(Some(Pending {
+                base: value,
+                functions: Vec::new(),
+            })),
+            forcing: Cell::new(false),
+        }
+    }
+
+    /// Queue the function given as a parameter, and return self.
+    ///
+    /// If the value has already been accessed, the function is applied immediately.
+    pub fn apply_lazy<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R + 'a,
+    {
+        let mut receiver = self;
+        match receiver.value.get_mut() {
+            Some(value) => {
+                f(value);
+            }
+            None => {
+                let pending = receiver.pending.get_mut().as_mut();
+                pending.expect(POISONED).functions.push(Box::new(move |it| {
+                    f(it);
+                }));
+            }
+        }
+        receiver
+    }
+
+    /// Return true if the pending functions have already been applied.
+    pub fn is_applied(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Apply the pending functions if needed, and return the value.
+    pub fn into_inner(self) -> T {
+        let mut receiver = self;
+        match receiver.value.take() {
+            Some(value) => value,
+            None => receiver.pending.take().expect(POISONED).run(),
+        }
+    }
+
+    fn force(&self) -> &T {
+        self.value.get_or_init(|| {
+            if self.forcing.replace(true) {
+                panic!("{}", REENTERED);
+            }
+            let _forcing = Forcing(&self.forcing);
+            self.pending.take().expect(POISONED).run()
+        })
+    }
+}
+
+impl<T> Deref for LazyApplied<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+impl<T> DerefMut for LazyApplied<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.force();
+        self.value.get_mut().expect(POISONED)
+    }
+}
+
+impl<T> From<T> for LazyApplied<'_, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LazyApplied<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("LazyApplied").field(value).finish(),
+            None => f.write_str("LazyApplied(<pending>)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_lazy_applied() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = LazyApplied::new(HashMap::new())
+            .apply_lazy(|it| it.insert(1, "one"))
+            .apply_lazy(|it| it.insert(2, "two"));
+        assert!(!map.is_applied());
+        assert_eq!(*map, exact_map);
+        assert!(map.is_applied());
+        assert_eq!(map.into_inner(), exact_map);
+    }
+
+    #[test]
+    fn test_lazy_applied_unused_case() {
+        let count = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&count);
+        let numbers = LazyApplied::new(vec![1]).apply_lazy(move |it| {
+            counter.set(counter.get() + 1);
+            it.push(2);
+        });
+        drop(numbers);
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn test_lazy_applied_after_access() {
+        let mut numbers = LazyApplied::from(vec![1]).apply_lazy(|it| it.push(2));
+        numbers.push(3);
+        let numbers = numbers.apply_lazy(|it| it.push(4));
+        assert_eq!(format!("{:?}", numbers), "LazyApplied([1, 2, 3, 4])");
+        assert_eq!(numbers.into_inner(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_lazy_applied_borrowing_case() {
+        let suffix = String::from(" and Hachi");
+        let names = LazyApplied::new(String::from("Pochi")).apply_lazy(|it| it.push_str(&suffix));
+        assert_eq!(names.into_inner(), "Pochi and Hachi");
+        assert_eq!(suffix, " and Hachi");
+    }
+
+    #[test]
+    #[should_panic(expected = "has accessed the value being applied")]
+    fn test_lazy_applied_reentrant_case() {
+        let numbers = Rc::new_cyclic(|weak: &std::rc::Weak<LazyApplied<'static, Vec<i32>>>| {
+            let weak = weak.clone();
+            LazyApplied::new(vec![1]).apply_lazy(move |it| {
+                let len = weak.upgrade().map(|numbers| numbers.len());
+                it.extend(len.map(|len| len as i32));
+            })
+        });
+        assert_eq!(numbers.len(), 1);
+    }
+}
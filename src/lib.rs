@@ -1,3 +1,139 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(fn_traits, unboxed_closures))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod applicable_mut;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod applier;
+pub mod apply_fn;
+#[cfg(feature = "std")]
+mod apply_handle;
+mod args;
+#[cfg(feature = "async")]
+mod async_applicable;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+mod async_lock;
+mod atomic;
+mod buildable;
+#[cfg(feature = "alloc")]
+mod cow;
+#[cfg(feature = "critical-section")]
+mod critical;
+mod deref;
+#[cfg(feature = "alloc")]
+mod downcast;
+#[cfg(feature = "alloc")]
+mod dyn_applicable;
+mod error;
+mod frozen;
+#[cfg(any(feature = "async", feature = "native-async"))]
+mod future_applicable;
+#[cfg(feature = "alloc")]
+mod history;
+mod init;
+mod interior;
+#[cfg(feature = "alloc")]
+mod lazy;
+#[cfg(feature = "std")]
+mod lock;
+mod macros;
+#[cfg(feature = "alloc")]
+mod make_mut;
+#[cfg(feature = "native-async")]
+pub mod native_async;
+#[cfg(feature = "parking_lot")]
+mod parking_lot_lock;
+mod pinned;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "rayon")]
+mod rayon_applicable;
+mod retry;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+mod rt;
+#[cfg(feature = "stream")]
+mod stream_applicable;
+mod strict;
+#[cfg(feature = "std")]
+mod tls;
+mod tracked;
+mod try_applicable;
+mod versioned;
+#[cfg(feature = "alloc")]
+mod weak;
+
+pub use applicable_mut::ApplicableMut;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use applier::{Applier, ApplierBuilder};
+#[cfg(feature = "std")]
+pub use apply_handle::{ApplyHandle, ApplySender, SendError};
+pub use args::ApplyArgs;
+#[cfg(feature = "async")]
+pub use async_applicable::{AsyncApplicable, BoxFuture};
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+pub use async_lock::AsyncLockApplicable;
+pub use atomic::AtomicApplicable;
+pub use buildable::Buildable;
+#[cfg(feature = "alloc")]
+pub use cow::CowApplicable;
+#[cfg(feature = "critical-section")]
+pub use critical::CriticalSectionApplicable;
+pub use deref::ApplicableDeref;
+#[cfg(feature = "alloc")]
+pub use downcast::DowncastApplicable;
+#[cfg(feature = "alloc")]
+pub use dyn_applicable::{BoxError, DynApplicable, LocalApplyFn, TryApplyFn};
+#[cfg(feature = "alloc")]
+pub use error::ContextError;
+pub use error::{ApplyError, Elapsed, RollbackError};
+pub use frozen::Frozen;
+#[cfg(any(feature = "async", feature = "native-async"))]
+pub use future_applicable::FutureApplicable;
+#[cfg(feature = "alloc")]
+pub use history::History;
+pub use init::{Init, InitApplicable, Uninit};
+pub use interior::InteriorApplicable;
+#[cfg(feature = "alloc")]
+pub use lazy::LazyApplied;
+#[cfg(feature = "std")]
+pub use lock::{apply_static, LockApplicable};
+#[doc(hidden)]
+pub use macros::__apply_to;
+#[cfg(feature = "alloc")]
+pub use make_mut::MakeMutApplicable;
+#[cfg(feature = "parking_lot")]
+pub use parking_lot_lock::InfallibleLockApplicable;
+pub use pinned::PinApplicable;
+#[cfg(feature = "std")]
+pub use pool::{Pool, Pooled};
+#[cfg(feature = "rayon")]
+pub use rayon_applicable::ParApplicable;
+pub use retry::{Backoff, RetryPolicy};
+#[cfg(feature = "stream")]
+pub use stream_applicable::{MapApply, StreamApplicable};
+pub use strict::UnitReturn;
+#[cfg(feature = "std")]
+pub use tls::apply_scoped_tls;
+pub use tracked::Tracked;
+pub use try_applicable::TryApplicable;
+pub use versioned::Versioned;
+#[cfg(feature = "alloc")]
+pub use weak::WeakApplicable;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::convert::TryInto;
+use core::ops::ControlFlow;
+
+/// The boxed function which can be sent to another thread and applied to `T` there.
+#[cfg(feature = "alloc")]
+pub type ApplyFn<T> = Box<dyn FnOnce(&mut T) + Send>;
+
 /// Allows you to apply any function given as a parameter to the object.
 ///
 /// As you are able to connect operations to the object with chains, it allow you to describe the
@@ -28,7 +164,7 @@
 /// assert_eq!(dog, exact_dog);
 /// ```
 ///
-pub trait Applicable {
+pub trait Applicable: Sized {
     /// Apply the function given as a parameter to self.
     ///
     /// # Examples
@@ -78,7 +214,10 @@ pub trait Applicable {
 
     /// Apply apply_with_param repeatedly to multiple parameters.
     ///
-    /// # Example
+    /// The parameters can be given by anything iterable, like `Vec`s, arrays, ranges and
+    /// iterator adaptors.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use apply_method::*;
@@ -90,114 +229,2809 @@ pub trait Applicable {
     ///     .apply_with_params(PathBuf::push, vec!["src", "lib.rs"]);
     /// assert_eq!(path, exact_path);
     /// ```
-    fn apply_with_params<F, P, R>(self, f: F, p: Vec<P>) -> Self
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().apply_with_params(Vec::push, (1..=3).map(|n| n * 10));
+    /// assert_eq!(numbers, vec![10, 20, 30]);
+    /// ```
+    fn apply_with_params<F, P, I, R>(self, f: F, p: I) -> Self
     where
+        I: IntoIterator<Item = P>,
         F: Fn(&mut Self, P) -> R;
-}
 
-impl<T> Applicable for T {
-    fn apply<F, R>(self, f: F) -> Self
+    /// Apply the fallible function given as a parameter to self.
+    ///
+    /// If the function returns an error, the chain is short-circuited and the error is returned
+    /// instead of self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new()
+    ///     .try_apply(|it| "1".parse::<i32>().map(|n| it.push(n)))
+    ///     .and_then(|it| it.try_apply(|it| "2".parse::<i32>().map(|n| it.push(n))));
+    /// assert_eq!(numbers, Ok(vec![1, 2]));
+    /// ```
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().try_apply(|it| "one".parse::<i32>().map(|n| it.push(n)));
+    /// assert!(numbers.is_err());
+    /// ```
+    ///
+    fn try_apply<F, R, E>(self, f: F) -> Result<Self, E>
     where
-        F: FnOnce(&mut Self) -> R,
-    {
-        let mut receiver = self;
-        f(&mut receiver);
-        receiver
-    }
+        F: FnOnce(&mut Self) -> Result<R, E>;
 
-    fn apply_with_param<F, P, R>(self, f: F, p: P) -> Self
+    /// Apply the fallible function with one parameter given as a parameter to self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::<i32>::new().try_apply_with_param(Vec::try_reserve, 10);
+    /// assert!(numbers.unwrap().capacity() >= 10);
+    /// ```
+    ///
+    fn try_apply_with_param<F, P, R, E>(self, f: F, p: P) -> Result<Self, E>
     where
-        F: FnOnce(&mut Self, P) -> R,
-    {
-        let mut receiver = self;
-        f(&mut receiver, p);
-        receiver
-    }
+        F: FnOnce(&mut Self, P) -> Result<R, E>;
 
-    fn apply_with_params<F, P, R>(self, f: F, p: Vec<P>) -> Self
+    /// Apply try_apply_with_param repeatedly to multiple parameters.
+    ///
+    /// The application stops at the first failing parameter, and the error is returned with the
+    /// position of that parameter. See try_apply_with_params_retry and
+    /// try_apply_with_params_retry_collect for the counterparts which retry each failing
+    /// parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().try_apply_with_params(
+    ///     |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+    ///     vec!["1", "2", "3"],
+    /// );
+    /// assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    /// ```
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().try_apply_with_params(
+    ///     |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+    ///     vec!["1", "two", "3"],
+    /// );
+    /// assert_eq!(numbers.unwrap_err().index, 1);
+    /// ```
+    fn try_apply_with_params<F, P, I, R, E>(self, f: F, p: I) -> Result<Self, ApplyError<E>>
     where
-        F: Fn(&mut Self, P) -> R,
-    {
-        let mut receiver = self;
-        for param in p {
-            f(&mut receiver, param);
-        }
-        receiver
-    }
-}
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::HashMap;
-    use std::path::PathBuf;
+    /// Apply try_apply_with_param repeatedly to multiple parameters, continuing after failures.
+    ///
+    /// Unlike try_apply_with_params, all parameters are applied even if some of them fail, and
+    /// every error is returned together with the position of its parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().try_apply_with_params_collect(
+    ///     |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+    ///     vec!["1", "two", "3", "four"],
+    /// );
+    /// let indices: Vec<usize> = numbers.unwrap_err().into_iter().map(|(i, _)| i).collect();
+    /// assert_eq!(indices, vec![1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_apply_with_params_collect<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+    ) -> Result<Self, Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>;
 
-    #[test]
-    fn test_apply() {
-        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        exact_path.push("src/lib.rs");
-        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).apply(|it| it.push("src/lib.rs"));
-        assert_eq!(path, exact_path);
-    }
+    /// Apply the fallible function given as a parameter to self, and apply the fallback function
+    /// instead if it fails.
+    ///
+    /// Note that the fallback function receives self in the state left by the failed function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new()
+    ///     .try_apply_or(|it| "one".parse::<i32>().map(|n| it.push(n)), |it| it.push(0));
+    /// assert_eq!(numbers, vec![0]);
+    /// ```
+    fn try_apply_or<F, G, R, S, E>(self, f: F, fallback: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+        G: FnOnce(&mut Self) -> S;
 
-    #[test]
-    fn test_apply_twice() {
-        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        exact_path.push("src/lib.rs");
-        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .apply(|it| it.push("src"))
-            .apply(|it| it.push("lib.rs"));
-        assert_eq!(path, exact_path);
-    }
+    /// Apply the fallible function given as a parameter to self, and apply the fallback function
+    /// with the error instead if it fails.
+    ///
+    /// Note that the fallback function receives self in the state left by the failed function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let messages = Vec::new().try_apply_or_else(
+    ///     |it| "one".parse::<i32>().map(|n| it.push(n.to_string())),
+    ///     |it, e| it.push(e.to_string()),
+    /// );
+    /// assert_eq!(messages, vec!["invalid digit found in string".to_string()]);
+    /// ```
+    fn try_apply_or_else<F, G, R, S, E>(self, f: F, fallback: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+        G: FnOnce(&mut Self, E) -> S;
 
-    #[test]
-    fn test_apply_ohter_usage() {
-        #[derive(Debug, PartialEq)]
-        struct Dog {
-            name: String,
-            size: String,
-        }
-        impl Dog {
-            fn new() -> Self {
-                Self {
-                    name: "Pochi".to_string(),
-                    size: "Middle".to_string(),
-                }
-            }
-        }
-        let mut exact_dog = Dog::new();
-        exact_dog.size = "Big".to_string();
-        let dog = Dog::new().apply(|it| it.size = "Big".to_string());
-        assert_eq!(dog, exact_dog);
-    }
+    /// Apply the fallible function given as a parameter to self, attaching the message which
+    /// identifies this step to the error if it fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new()
+    ///     .try_apply_context("parse first", |it| "1".parse::<i32>().map(|n| it.push(n)))
+    ///     .and_then(|it| {
+    ///         it.try_apply_context("parse second", |it| "two".parse::<i32>().map(|n| it.push(n)))
+    ///     });
+    /// let error = numbers.unwrap_err();
+    /// assert_eq!(error.context, "parse second");
+    /// assert_eq!(error.to_string(), "parse second: invalid digit found in string");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_apply_context<C, F, R, E>(self, context: C, f: F) -> Result<Self, ContextError<E>>
+    where
+        C: core::fmt::Display,
+        F: FnOnce(&mut Self) -> Result<R, E>;
 
-    #[test]
-    fn test_apply_param() {
-        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        exact_path.push("src/lib.rs");
-        let path =
-            PathBuf::from(env!("CARGO_MANIFEST_DIR")).apply_with_param(PathBuf::push, "src/lib.rs");
-        assert_eq!(path, exact_path);
-    }
+    /// Apply the fallible function given as a parameter to self as a transaction.
+    ///
+    /// Self is cloned before the function is applied, and if the function fails, the clone is
+    /// returned with the error so that the failed step never leaves the value half-mutated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = vec![1].try_apply_transactional(|it| {
+    ///     it.push(2);
+    ///     "three".parse::<i32>().map(|n| it.push(n))
+    /// });
+    /// assert_eq!(numbers.unwrap_err().value, vec![1]);
+    /// ```
+    fn try_apply_transactional<F, R, E>(self, f: F) -> Result<Self, RollbackError<Self, E>>
+    where
+        Self: Clone,
+        F: FnOnce(&mut Self) -> Result<R, E>;
 
-    #[test]
-    fn test_apply_params() {
-        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        exact_path.push("src");
-        exact_path.push("lib.rs");
-        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .apply_with_params(PathBuf::push, vec!["src", "lib.rs"]);
-        assert_eq!(path, exact_path);
-    }
+    /// Apply the fallible function given as a parameter to self, retrying it according to the
+    /// policy while it fails.
+    ///
+    /// The function receives self in the state left by the previous failed attempt. If every
+    /// attempt fails, the error of the last attempt is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::time::Duration;
+    /// let mut inputs = vec!["3", "two", "one"];
+    /// let numbers = Vec::new().try_apply_retry(
+    ///     |it| inputs.pop().unwrap().parse::<i32>().map(|n| it.push(n)),
+    ///     RetryPolicy::fixed(3, Duration::from_millis(1)),
+    /// );
+    /// assert_eq!(numbers, Ok(vec![3]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_apply_retry<F, R, E>(self, f: F, policy: RetryPolicy) -> Result<Self, E>
+    where
+        F: FnMut(&mut Self) -> Result<R, E>;
 
-    #[test]
-    fn test_apply_non_unit_return_method_case() {
-        let mut exact_map = HashMap::new();
-        exact_map.insert(1, "one");
-        exact_map.insert(2, "two");
-        let map = HashMap::new()
-            .apply(|it| it.insert(1, "one"))
-            .apply(|it| it.insert(2, "two"));
+    /// Apply the function returning Option given as a parameter to self.
+    ///
+    /// If the function returns None, the chain is short-circuited and None is returned instead of
+    /// self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let words = vec!["apple", "banana"];
+    /// let initials = String::new().try_apply_opt(|it| {
+    ///     it.push(words.first()?.chars().next()?);
+    ///     it.push(words.get(1)?.chars().next()?);
+    ///     Some(())
+    /// });
+    /// assert_eq!(initials, Some("ab".to_string()));
+    /// ```
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let words: Vec<&str> = vec![];
+    /// let initials = String::new().try_apply_opt(|it| {
+    ///     it.push(words.first()?.chars().next()?);
+    ///     Some(())
+    /// });
+    /// assert_eq!(initials, None);
+    /// ```
+    ///
+    fn try_apply_opt<F, R>(self, f: F) -> Option<Self>
+    where
+        F: FnOnce(&mut Self) -> Option<R>;
+
+    /// Apply the function given as a parameter to self only if the condition is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let verbose = false;
+    /// let args = vec!["build"]
+    ///     .apply_if(verbose, |it| it.push("--verbose"))
+    ///     .apply_if(!verbose, |it| it.push("--quiet"));
+    /// assert_eq!(args, vec!["build", "--quiet"]);
+    /// ```
+    fn apply_if<F, R>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply the first function given as a parameter to self if the condition is true, otherwise
+    /// apply the second one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let release = true;
+    /// let args = vec!["build"].apply_if_else(
+    ///     release,
+    ///     |it| it.push("--release"),
+    ///     |it| it.push("--debug"),
+    /// );
+    /// assert_eq!(args, vec!["build", "--release"]);
+    /// ```
+    fn apply_if_else<F, G, R, S>(self, cond: bool, then_f: F, else_f: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+        G: FnOnce(&mut Self) -> S;
+
+    /// Apply the function given as a parameter to self only if the condition is false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let quiet = false;
+    /// let args = vec!["build"].apply_unless(quiet, |it| it.push("--verbose"));
+    /// assert_eq!(args, vec!["build", "--verbose"]);
+    /// ```
+    fn apply_unless<F, R>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply the function given as a parameter to self only if the predicate over self is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let path = PathBuf::from("src/lib")
+    ///     .apply_when(|it| it.extension().is_none(), |it| it.set_extension("rs"));
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_when<P, F, R>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply the function given as a parameter to self with the value of the option only if it
+    /// is Some.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let file_name = Some("lib.rs");
+    /// let sub_dir: Option<&str> = None;
+    /// let path = PathBuf::from("src")
+    ///     .apply_if_some(sub_dir, |it, dir| it.push(dir))
+    ///     .apply_if_some(file_name, |it, name| it.push(name));
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_if_some<F, P, R>(self, opt: Option<P>, f: F) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R;
+
+    /// Apply the function given as a parameter to self with the value of the result only if it
+    /// is Ok.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new()
+    ///     .apply_if_ok("1".parse::<i32>(), |it, n| it.push(n))
+    ///     .apply_if_ok("two".parse::<i32>(), |it, n| it.push(n));
+    /// assert_eq!(numbers, vec![1]);
+    /// ```
+    fn apply_if_ok<F, P, R, E>(self, res: Result<P, E>, f: F) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R;
+
+    /// Apply the first function given as a parameter to self with the value of the result if it
+    /// is Ok, otherwise apply the second one with the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let mut errors = Vec::new();
+    /// let numbers = Vec::new()
+    ///     .apply_if_ok_else("1".parse::<i32>(), |it, n| it.push(n), |_, e| errors.push(e))
+    ///     .apply_if_ok_else("two".parse::<i32>(), |it, n| it.push(n), |_, e| errors.push(e));
+    /// assert_eq!(numbers, vec![1]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn apply_if_ok_else<F, G, P, R, S, E>(self, res: Result<P, E>, f: F, on_err: G) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+        G: FnOnce(&mut Self, E) -> S;
+
+    /// Apply the function given as a parameter to self only in debug builds.
+    ///
+    /// In release builds, where `debug_assertions` is disabled, this is a no-op and the function
+    /// is optimized out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = vec![3, 1, 2]
+    ///     .apply(|it| it.sort())
+    ///     .apply_when_debug(|it| assert!(it.windows(2).all(|w| w[0] <= w[1])));
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// ```
+    fn apply_when_debug<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply the function given as a parameter to self repeatedly while the predicate over self
+    /// is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let path = PathBuf::from("src/target/debug/build")
+    ///     .apply_while(|it| it.ends_with("build") || it.ends_with("debug"), |it| it.pop());
+    /// assert_eq!(path, PathBuf::from("src/target"));
+    /// ```
+    fn apply_while<P, F, R>(self, pred: P, f: F) -> Self
+    where
+        P: FnMut(&Self) -> bool,
+        F: FnMut(&mut Self) -> R;
+
+    /// Apply the function given as a parameter to self repeatedly until self stops changing.
+    ///
+    /// Note that this never returns if the function keeps changing self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let text = "a//b///c".to_string().apply_until_fixpoint(|it| *it = it.replace("//", "/"));
+    /// assert_eq!(text, "a/b/c");
+    /// ```
+    fn apply_until_fixpoint<F, R>(self, f: F) -> Self
+    where
+        Self: Clone + PartialEq,
+        F: FnMut(&mut Self) -> R;
+
+    /// Move self to a new thread, apply the function given as a parameter to it there, and
+    /// return the handle to join the thread.
+    ///
+    /// Joining the handle gives self back, so expensive initialization can run off-thread and
+    /// rejoin the chain later.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let handle = Vec::new().apply_spawn(|it| it.extend((1..=3).map(|n| n * n)));
+    /// let numbers = handle.join().unwrap().apply(|it| it.push(0));
+    /// assert_eq!(numbers, vec![1, 4, 9, 0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn apply_spawn<F, R>(self, f: F) -> std::thread::JoinHandle<Self>
+    where
+        Self: Send + 'static,
+        F: FnOnce(&mut Self) -> R + Send + 'static;
+
+    /// Apply every function received from the channel to self until the channel is closed.
+    ///
+    /// This is useful to collect mutations from worker threads into the one owner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::mpsc;
+    /// let (sender, receiver) = mpsc::channel::<ApplyFn<Vec<i32>>>();
+    /// for n in 0..3 {
+    ///     let sender = sender.clone();
+    ///     std::thread::spawn(move || sender.send(Box::new(move |it| it.push(n))).unwrap());
+    /// }
+    /// drop(sender);
+    /// let numbers = Vec::new().apply_from_channel(receiver).apply(|it| it.sort());
+    /// assert_eq!(numbers, vec![0, 1, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn apply_from_channel(self, rx: std::sync::mpsc::Receiver<ApplyFn<Self>>) -> Self;
+
+    /// Seal self so that it can't be mutated anymore.
+    ///
+    /// This is the terminal step of a chain. The returned [`Frozen`] only gives shared access to
+    /// the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let names = vec!["Pochi"].apply(|it| it.push("Hachi")).seal();
+    /// assert_eq!(names.len(), 2);
+    /// ```
+    fn seal(self) -> Frozen<Self>;
+
+    /// Take self out of the mutable reference, apply the owning function given as a parameter,
+    /// and put the result back.
+    ///
+    /// `Default::default()` is left in place while the function runs, so it can also be used when
+    /// only `&mut Self` is available.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// struct Work {
+    ///     path: PathBuf,
+    /// }
+    /// let mut work = Work { path: PathBuf::from("src") };
+    /// work.path.apply_take(|it| it.join("lib.rs"));
+    /// assert_eq!(work.path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_take<F>(&mut self, f: F) -> &mut Self
+    where
+        Self: Default,
+        F: FnOnce(Self) -> Self;
+
+    /// Apply the owning function given as a parameter to self, and return the result.
+    ///
+    /// This allows you to call consuming builder methods or to replace the value entirely in the
+    /// middle of a chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let path = PathBuf::from("src")
+    ///     .apply_owned(|it| it.join("lib.rs"))
+    ///     .apply(|it| it.set_extension("txt"));
+    /// assert_eq!(path, PathBuf::from("src/lib.txt"));
+    /// ```
+    fn apply_owned<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self;
+
+    /// Apply the function given as a parameter to self, and return self with the result of the
+    /// function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashMap;
+    /// let (ages, previous) = HashMap::new()
+    ///     .apply(|it| it.insert("Pochi", 3))
+    ///     .apply_and(|it| it.insert("Pochi", 4));
+    /// assert_eq!(previous, Some(3));
+    /// assert_eq!(ages["Pochi"], 4);
+    /// ```
+    fn apply_and<F, R>(self, f: F) -> (Self, R)
+    where
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply the function given as a parameter to self with each parameter, and return self with
+    /// the results of all the calls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashSet;
+    /// let (names, inserted) = HashSet::new()
+    ///     .apply_with_params_collect(HashSet::insert, vec!["Pochi", "Hachi", "Pochi"]);
+    /// assert_eq!(names.len(), 2);
+    /// assert_eq!(inserted, vec![true, true, false]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn apply_with_params_collect<F, P, I, R>(self, f: F, p: I) -> (Self, Vec<R>)
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> R;
+
+    /// Apply the function given as a parameter to self with the tuple of arguments.
+    ///
+    /// Methods taking more than one parameter can be used without wrapping them in a closure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let names = vec!["Pochi", "Shiro"]
+    ///     .apply_with_args(Vec::insert, (1, "Hachi"))
+    ///     .apply_with_args(Vec::resize, (4, "Kuro"));
+    /// assert_eq!(names, vec!["Pochi", "Hachi", "Shiro", "Kuro"]);
+    /// ```
+    fn apply_with_args<F, A, R>(self, f: F, args: A) -> Self
+    where
+        A: ApplyArgs<Self, F, R>;
+
+    /// Apply the function given as a parameter to self with a reference to each parameter in the
+    /// slice.
+    ///
+    /// The parameters are only borrowed, so large or non-Clone parameters can be shared across
+    /// several chains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let parts = [PathBuf::from("src"), PathBuf::from("lib.rs")];
+    /// let source = PathBuf::from("crate").apply_with_params_ref(|it, p| it.push(p), &parts);
+    /// let backup = PathBuf::from("backup").apply_with_params_ref(|it, p| it.push(p), &parts);
+    /// assert_eq!(source, PathBuf::from("crate/src/lib.rs"));
+    /// assert_eq!(backup, PathBuf::from("backup/src/lib.rs"));
+    /// ```
+    fn apply_with_params_ref<F, P, R>(self, f: F, p: &[P]) -> Self
+    where
+        F: Fn(&mut Self, &P) -> R;
+
+    /// Apply the function with two parameters given as a parameter to self with each pair of
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashMap;
+    /// let ages =
+    ///     HashMap::new().apply_with_param_pairs(HashMap::insert, vec![("Pochi", 3), ("Hachi", 5)]);
+    /// assert_eq!(ages["Hachi"], 5);
+    /// ```
+    fn apply_with_param_pairs<F, A, B, I, R>(self, f: F, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (A, B)>,
+        F: Fn(&mut Self, A, B) -> R;
+
+    /// Apply each function to self with the parameter at the same position.
+    ///
+    /// The functions and the parameters are walked in lockstep, and the application stops when
+    /// either of them runs out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let steps: Vec<fn(&mut String, &str)> = vec![String::push_str, |it, s| it.insert_str(0, s)];
+    /// let text = String::from("b").apply_zipped(steps, vec!["c", "a"]);
+    /// assert_eq!(text, "abc");
+    /// ```
+    fn apply_zipped<FS, F, PS, P, R>(self, fs: FS, ps: PS) -> Self
+    where
+        FS: IntoIterator<Item = F>,
+        F: FnOnce(&mut Self, P) -> R,
+        PS: IntoIterator<Item = P>;
+
+    /// Apply every function given as a parameter to self in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let suffixes = ["lib", ".", "rs"];
+    /// let path = String::from("src/")
+    ///     .apply_all(suffixes.iter().map(|s| move |it: &mut String| it.push_str(s)));
+    /// assert_eq!(path, "src/lib.rs");
+    /// ```
+    fn apply_all<FS, F, R>(self, fs: FS) -> Self
+    where
+        FS: IntoIterator<Item = F>,
+        F: FnOnce(&mut Self) -> R;
+
+    /// Apply every boxed function given as a parameter to self in order.
+    ///
+    /// This is the dynamic counterpart of apply_all, for the functions assembled at runtime,
+    /// e.g. from configuration or plugins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let verbose = true;
+    /// let mut operations: Vec<LocalApplyFn<Vec<&str>>> = vec![Box::new(|it| it.push("run"))];
+    /// if verbose {
+    ///     operations.push(Box::new(|it| it.push("--verbose")));
+    /// }
+    /// let args = Vec::new().apply_batch(operations);
+    /// assert_eq!(args, vec!["run", "--verbose"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn apply_batch(self, ops: Vec<LocalApplyFn<'_, Self>>) -> Self;
+
+    /// Apply apply_with_param repeatedly to multiple parameters, passing the zero-based position
+    /// of each parameter as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let csv = String::new().apply_with_params_enumerated(
+    ///     |it, i, name| {
+    ///         if i > 0 {
+    ///             it.push(',');
+    ///         }
+    ///         it.push_str(name);
+    ///     },
+    ///     ["Pochi", "Hachi", "Shiro"],
+    /// );
+    /// assert_eq!(csv, "Pochi,Hachi,Shiro");
+    /// ```
+    fn apply_with_params_enumerated<F, P, I, R>(self, f: F, p: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, usize, P) -> R;
+
+    /// Apply the function given as a parameter to self with the accumulator, and return self with
+    /// the new accumulator.
+    ///
+    /// The accumulator can be threaded through several steps to collect statistics while still
+    /// returning the configured value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let (text, written) = String::new().apply_fold(0, |it, n| {
+    ///     it.push_str("Pochi");
+    ///     n + "Pochi".len()
+    /// });
+    /// let (text, written) = text.apply_fold(written, |it, n| {
+    ///     it.push_str(" Hachi");
+    ///     n + " Hachi".len()
+    /// });
+    /// assert_eq!(written, text.len());
+    /// ```
+    fn apply_fold<F, A>(self, init: A, f: F) -> (Self, A)
+    where
+        F: FnOnce(&mut Self, A) -> A;
+
+    /// Apply the function given as a parameter to self with the shared environment.
+    ///
+    /// The environment is passed by reference to the function, so the same configuration can be
+    /// used by several steps without being captured by each closure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::{Path, PathBuf};
+    /// struct Env {
+    ///     root: PathBuf,
+    /// }
+    /// let env = Env { root: PathBuf::from("/srv") };
+    /// let paths = Vec::new()
+    ///     .apply_with_env(&env, |it, env| it.push(env.root.join("www")))
+    ///     .apply_with_env(&env, |it, env| it.push(env.root.join("log")));
+    /// assert_eq!(paths, vec![Path::new("/srv/www"), Path::new("/srv/log")]);
+    /// ```
+    fn apply_with_env<E, F, R>(self, env: &E, f: F) -> Self
+    where
+        E: ?Sized,
+        F: FnOnce(&mut Self, &E) -> R;
+
+    /// Apply the function given as a parameter to self together with another value.
+    ///
+    /// This is useful to copy the selected state from another object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// #[derive(Default)]
+    /// struct Window {
+    ///     title: String,
+    ///     width: u32,
+    /// }
+    /// let template = Window { title: "Pochi".to_string(), width: 640 };
+    /// let window = Window::default().apply_with(&template, |it, other| it.width = other.width);
+    /// assert_eq!(window.width, 640);
+    /// assert!(window.title.is_empty());
+    /// ```
+    fn apply_with<U, F, R>(self, other: &U, f: F) -> Self
+    where
+        U: ?Sized,
+        F: FnOnce(&mut Self, &U) -> R;
+
+    /// Apply the function given as a parameter to self the given number of times.
+    ///
+    /// The function also receives the zero-based iteration index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let rows = Vec::new().apply_n(3, |it, i| it.push(vec![0; i + 1]));
+    /// assert_eq!(rows, vec![vec![0], vec![0, 0], vec![0, 0, 0]]);
+    /// ```
+    fn apply_n<F, R>(self, times: usize, f: F) -> Self
+    where
+        F: FnMut(&mut Self, usize) -> R;
+
+    /// Apply apply_with_param repeatedly to multiple parameters until the function breaks.
+    ///
+    /// The function returns `ControlFlow::Break` to stop the loop early. Self is returned with the
+    /// break value, or `None` if all the parameters were applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::ops::ControlFlow;
+    /// let (total, overflowed) = 0u8.apply_with_params_flow(
+    ///     |it, n: u8| match it.checked_add(n) {
+    ///         Some(sum) => {
+    ///             *it = sum;
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///         None => ControlFlow::Break(n),
+    ///     },
+    ///     [100, 100, 100],
+    /// );
+    /// assert_eq!(total, 200);
+    /// assert_eq!(overflowed, Some(100));
+    /// ```
+    fn apply_with_params_flow<F, P, I, B>(self, f: F, p: I) -> (Self, Option<B>)
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> ControlFlow<B>;
+
+    /// Apply the function given as a parameter to self with each key and value of the map.
+    ///
+    /// Any iterable of key-value pairs can be given, e.g. `HashMap`, `BTreeMap` or
+    /// `std::env::vars()`, so configuration maps can be folded into self in one step. This is
+    /// the same as apply_with_param_pairs, named for maps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::BTreeMap;
+    /// let headers = BTreeMap::new()
+    ///     .apply(|it| it.insert("Accept", "text/html"))
+    ///     .apply(|it| it.insert("Host", "example.com"));
+    /// let request = String::from("GET / HTTP/1.1\r\n")
+    ///     .apply_keyed_params(|it, k, v| it.push_str(&format!("{}: {}\r\n", k, v)), headers);
+    /// assert_eq!(request, "GET / HTTP/1.1\r\nAccept: text/html\r\nHost: example.com\r\n");
+    /// ```
+    fn apply_keyed_params<F, K, V, I, R>(self, f: F, map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: Fn(&mut Self, K, V) -> R;
+
+    /// Apply the function given as a parameter to self with each chunk of the parameters.
+    ///
+    /// The function receives up to `chunk_size` parameters per call, so bulk APIs like `extend`
+    /// can be fed efficiently. The last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let (rows, batches) = (Vec::new(), std::cell::Cell::new(0));
+    /// let rows = rows.apply_chunked_params(
+    ///     |it, chunk| {
+    ///         batches.set(batches.get() + 1);
+    ///         it.extend_from_slice(chunk);
+    ///     },
+    ///     &[1, 2, 3, 4, 5],
+    ///     2,
+    /// );
+    /// assert_eq!(rows, vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(batches.get(), 3);
+    /// ```
+    fn apply_chunked_params<F, P, R>(self, f: F, p: &[P], chunk_size: usize) -> Self
+    where
+        F: Fn(&mut Self, &[P]) -> R;
+
+    /// Apply apply_with_params, and call the progress callback with the index of the parameter
+    /// just applied and the total number of the parameters after every application.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let mut log = Vec::new();
+    /// let names = Vec::new().apply_with_progress(
+    ///     Vec::push,
+    ///     vec!["Pochi", "Hachi"],
+    ///     |index, total| log.push(format!("{}/{}", index + 1, total)),
+    /// );
+    /// assert_eq!(names, vec!["Pochi", "Hachi"]);
+    /// assert_eq!(log, vec!["1/2", "2/2"]);
+    /// ```
+    fn apply_with_progress<F, P, I, R, G>(self, f: F, p: I, progress: G) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: ExactSizeIterator,
+        F: Fn(&mut Self, P) -> R,
+        G: FnMut(usize, usize);
+
+    /// Apply the function given as a parameter to self `n` times, with the i-th parameter
+    /// generated on the fly by `gen(i)`.
+    ///
+    /// The parameters are computed lazily, so no collection has to be built beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let squares = Vec::new().apply_with_generated_params(4, |i| i * i, Vec::push);
+    /// assert_eq!(squares, vec![0, 1, 4, 9]);
+    /// ```
+    fn apply_with_generated_params<G, F, P, R>(self, n: usize, gen: G, f: F) -> Self
+    where
+        G: FnMut(usize) -> P,
+        F: Fn(&mut Self, P) -> R;
+
+    /// Apply apply_with_param converting the parameter with `Into` before calling the function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let names: Vec<String> = Vec::new().apply_with_param_into(Vec::push, "Pochi");
+    /// assert_eq!(names, vec!["Pochi".to_string()]);
+    /// ```
+    fn apply_with_param_into<F, P, Q, R>(self, f: F, p: P) -> Self
+    where
+        P: Into<Q>,
+        F: FnOnce(&mut Self, Q) -> R;
+
+    /// Apply apply_with_param to the function taking the parameter by reference, borrowing the
+    /// owned parameter internally.
+    ///
+    /// This works with any function whose second argument is `&Q` while the parameter implements
+    /// `Borrow<Q>`, e.g. `String::push_str` with a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let name = String::from("Pochi");
+    /// let greeting = String::from("Hello, ").apply_with_borrowed_param(String::push_str, name);
+    /// assert_eq!(greeting, "Hello, Pochi");
+    /// ```
+    fn apply_with_borrowed_param<F, P, Q, R>(self, f: F, p: P) -> Self
+    where
+        Q: ?Sized,
+        P: Borrow<Q>,
+        F: FnOnce(&mut Self, &Q) -> R;
+
+    /// Apply apply_with_param only if the parameter is Some, and skip the function otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let sub_dir: Option<&str> = None;
+    /// let path = PathBuf::from("src")
+    ///     .apply_with_opt_param(PathBuf::push, sub_dir)
+    ///     .apply_with_opt_param(PathBuf::push, Some("lib.rs"));
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_with_opt_param<F, P, R>(self, f: F, p: Option<P>) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R;
+
+    /// Apply the fallible function given as a parameter to self with each parameter in turn until
+    /// one of them succeeds.
+    ///
+    /// On success, self is returned together with the position of the parameter which worked.
+    /// If every parameter fails, or there are no parameters, all errors are returned together
+    /// with the positions of their parameters. Note that each attempt receives self in the state
+    /// left by the previous failed attempts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let config = String::new().try_apply_with_fallback_params(
+    ///     |it, path: &str| std::fs::read_to_string(path).map(|s| it.push_str(&s)),
+    ///     vec!["no/such/config.toml", "Cargo.toml"],
+    /// );
+    /// let (config, index) = config.unwrap();
+    /// assert_eq!(index, 1);
+    /// assert!(config.contains("[package]"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_apply_with_fallback_params<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+    ) -> Result<(Self, usize), Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>;
+
+    /// Apply try_apply_with_params, retrying each failing parameter according to the policy
+    /// before aborting.
+    ///
+    /// The parameters must be `Clone`, because every attempt receives its own clone of the
+    /// parameter. If every attempt for a parameter fails, the error of the last attempt is
+    /// returned with the position of that parameter.
+    ///
+    /// Note that self is not restored between attempts, so a retried function receives self in
+    /// the state left by the previous failed attempt, including any partial mutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::cell::Cell;
+    /// let flaky = Cell::new(true);
+    /// let numbers = Vec::new().try_apply_with_params_retry(
+    ///     |it, n: i32| {
+    ///         if n == 2 && flaky.replace(false) {
+    ///             return Err("timed out");
+    ///         }
+    ///         Ok(it.push(n))
+    ///     },
+    ///     vec![1, 2, 3],
+    ///     RetryPolicy::immediate(2),
+    /// );
+    /// assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_apply_with_params_retry<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+        policy: RetryPolicy,
+    ) -> Result<Self, ApplyError<E>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Clone,
+        F: Fn(&mut Self, P) -> Result<R, E>;
+
+    /// Apply try_apply_with_params_collect, retrying each failing parameter according to the
+    /// policy before skipping it.
+    ///
+    /// All parameters are applied, and the error of the last attempt for every skipped parameter
+    /// is returned together with its position. The parameters must be `Clone`, because every
+    /// attempt receives its own clone of the parameter.
+    ///
+    /// Note that self is not restored between attempts or after a skipped parameter, so a retried
+    /// function receives self in the state left by the previous failed attempt, including any
+    /// partial mutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers = Vec::new().try_apply_with_params_retry_collect(
+    ///     |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+    ///     vec!["1", "two", "3"],
+    ///     RetryPolicy::immediate(3),
+    /// );
+    /// let indices: Vec<usize> = numbers.unwrap_err().into_iter().map(|(i, _)| i).collect();
+    /// assert_eq!(indices, vec![1]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_apply_with_params_retry_collect<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+        policy: RetryPolicy,
+    ) -> Result<Self, Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Clone,
+        F: Fn(&mut Self, P) -> Result<R, E>;
+
+    /// Pass self to the function given as a parameter, and return its result.
+    ///
+    /// Unlike apply_owned, the function can return a different type, so a chain can continue
+    /// with or end in a transformed value without binding an intermediate variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let count = vec!["Pochi"]
+    ///     .apply(|it| it.push("Hachi"))
+    ///     .pipe(|it| it.len())
+    ///     .apply(|it| *it *= 10);
+    /// assert_eq!(count, 20);
+    /// ```
+    fn pipe<F, U>(self, f: F) -> U
+    where
+        F: FnOnce(Self) -> U;
+
+    /// Hand self over to the function given as a parameter, and return its result.
+    ///
+    /// This is an alias of pipe. Use pipe to pass through a transformation in the middle of a
+    /// chain, and run to finish a chain with a computation over the finished value, so the
+    /// intent is clear from the name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let len = String::from("Pochi")
+    ///     .apply(|it| it.push_str(" and Hachi"))
+    ///     .run(|it| it.len());
+    /// assert_eq!(len, 15);
+    /// ```
+    fn run<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Self) -> R;
+
+    /// Finish the chain by converting self with `TryInto`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let small = 200i32.apply(|it| *it += 50).try_into_applied::<u8>();
+    /// assert_eq!(small, Ok(250));
+    /// let small = 200i32.apply(|it| *it += 100).try_into_applied::<u8>();
+    /// assert!(small.is_err());
+    /// ```
+    fn try_into_applied<U>(self) -> Result<U, <Self as TryInto<U>>::Error>
+    where
+        Self: TryInto<U>;
+
+    /// Apply the function given as a parameter to self, and then convert self with `TryInto`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let bytes: Result<[u8; 2], _> = Vec::new().apply_then_try_into(|it| it.extend([1, 2]));
+    /// assert_eq!(bytes, Ok([1, 2]));
+    /// ```
+    fn apply_then_try_into<F, R, U>(self, f: F) -> Result<U, <Self as TryInto<U>>::Error>
+    where
+        Self: TryInto<U>,
+        F: FnOnce(&mut Self) -> R;
+
+    /// Return Some(self) if the predicate given as a parameter returns true, or None otherwise.
+    ///
+    /// This is the terminal step of a chain, which filters out the values that didn't reach a
+    /// valid state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let names = vec!["Pochi"]
+    ///     .apply(|it| it.push("Hachi"))
+    ///     .take_if(|it| it.len() > 1);
+    /// assert_eq!(names, Some(vec!["Pochi", "Hachi"]));
+    /// ```
+    fn take_if<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool;
+
+    /// Return Some(self) if the predicate given as a parameter returns false, or None otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let names = Vec::<&str>::new()
+    ///     .apply_with_params(Vec::push, Vec::new())
+    ///     .take_unless(Vec::is_empty);
+    /// assert_eq!(names, None);
+    /// ```
+    fn take_unless<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool;
+
+    /// Apply the function given as a parameter to self, and then convert self with `Into`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::path::PathBuf;
+    /// let path: PathBuf = String::from("src").apply_into(|it| it.push_str("/lib.rs"));
+    /// assert_eq!(path, PathBuf::from("src/lib.rs"));
+    /// ```
+    fn apply_into<F, R, U>(self, f: F) -> U
+    where
+        U: From<Self>,
+        F: FnOnce(&mut Self) -> R;
+
+    /// Pass the shared reference of self to the function given as a parameter, and return self.
+    ///
+    /// The function can't mutate self, which makes the intent clear for the inspection steps like
+    /// logging or metrics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let mut log = Vec::new();
+    /// let names = vec!["Pochi"]
+    ///     .apply_ref(|it| log.push(it.len()))
+    ///     .apply(|it| it.push("Hachi"))
+    ///     .apply_ref(|it| log.push(it.len()));
+    /// assert_eq!(names, vec!["Pochi", "Hachi"]);
+    /// assert_eq!(log, vec![1, 2]);
+    /// ```
+    fn apply_ref<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self) -> R;
+
+    /// Apply the function given as a parameter to self, and return self, only accepting the
+    /// functions which return `()`.
+    ///
+    /// Unlike apply, a non-unit result is not silently discarded, but rejected at compile time.
+    /// See [`UnitReturn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::new().apply_strict(|it| {
+    ///     let old = it.insert(1, "one");
+    ///     assert_eq!(old, None);
+    /// });
+    /// assert_eq!(map.get(&1), Some(&"one"));
+    /// ```
+    fn apply_strict<F, R>(self, f: F) -> Self
+    where
+        R: UnitReturn,
+        F: FnOnce(&mut Self) -> R;
+}
+
+impl<T> Applicable for T {
+    fn apply<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver);
+        receiver
+    }
+
+    fn apply_with_param<F, P, R>(self, f: F, p: P) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p);
+        receiver
+    }
+
+    fn apply_with_params<F, P, I, R>(self, f: F, p: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        for param in p {
+            f(&mut receiver, param);
+        }
+        receiver
+    }
+
+    fn try_apply<F, R, E>(self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        f(&mut receiver)?;
+        Ok(receiver)
+    }
+
+    fn try_apply_with_param<F, P, R, E>(self, f: F, p: P) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p)?;
+        Ok(receiver)
+    }
+
+    fn try_apply_with_params<F, P, I, R, E>(self, f: F, p: I) -> Result<Self, ApplyError<E>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        for (index, param) in p.into_iter().enumerate() {
+            f(&mut receiver, param).map_err(|source| ApplyError::new(index, source))?;
+        }
+        Ok(receiver)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_apply_with_params_collect<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+    ) -> Result<Self, Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        let mut errors = Vec::new();
+        for (index, param) in p.into_iter().enumerate() {
+            if let Err(error) = f(&mut receiver, param) {
+                errors.push((index, error));
+            }
+        }
+        if errors.is_empty() {
+            Ok(receiver)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn try_apply_or<F, G, R, S, E>(self, f: F, fallback: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+        G: FnOnce(&mut Self) -> S,
+    {
+        let mut receiver = self;
+        if f(&mut receiver).is_err() {
+            fallback(&mut receiver);
+        }
+        receiver
+    }
+
+    fn try_apply_or_else<F, G, R, S, E>(self, f: F, fallback: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+        G: FnOnce(&mut Self, E) -> S,
+    {
+        let mut receiver = self;
+        if let Err(error) = f(&mut receiver) {
+            fallback(&mut receiver, error);
+        }
+        receiver
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_apply_context<C, F, R, E>(self, context: C, f: F) -> Result<Self, ContextError<E>>
+    where
+        C: core::fmt::Display,
+        F: FnOnce(&mut Self) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        f(&mut receiver).map_err(|source| ContextError::new(context, source))?;
+        Ok(receiver)
+    }
+
+    fn try_apply_transactional<F, R, E>(self, f: F) -> Result<Self, RollbackError<Self, E>>
+    where
+        Self: Clone,
+        F: FnOnce(&mut Self) -> Result<R, E>,
+    {
+        let snapshot = self.clone();
+        let mut receiver = self;
+        match f(&mut receiver) {
+            Ok(_) => Ok(receiver),
+            Err(source) => Err(RollbackError::new(snapshot, source)),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn try_apply_retry<F, R, E>(self, mut f: F, policy: RetryPolicy) -> Result<Self, E>
+    where
+        F: FnMut(&mut Self) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        policy.retry(|| f(&mut receiver))?;
+        Ok(receiver)
+    }
+
+    fn try_apply_opt<F, R>(self, f: F) -> Option<Self>
+    where
+        F: FnOnce(&mut Self) -> Option<R>,
+    {
+        let mut receiver = self;
+        f(&mut receiver)?;
+        Some(receiver)
+    }
+
+    fn apply_if<F, R>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        if cond {
+            f(&mut receiver);
+        }
+        receiver
+    }
+
+    fn apply_if_else<F, G, R, S>(self, cond: bool, then_f: F, else_f: G) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+        G: FnOnce(&mut Self) -> S,
+    {
+        let mut receiver = self;
+        if cond {
+            then_f(&mut receiver);
+        } else {
+            else_f(&mut receiver);
+        }
+        receiver
+    }
+
+    fn apply_unless<F, R>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.apply_if(!cond, f)
+    }
+
+    fn apply_when<P, F, R>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(&mut Self) -> R,
+    {
+        let cond = pred(&self);
+        self.apply_if(cond, f)
+    }
+
+    fn apply_if_some<F, P, R>(self, opt: Option<P>, f: F) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        if let Some(p) = opt {
+            f(&mut receiver, p);
+        }
+        receiver
+    }
+
+    fn apply_if_ok<F, P, R, E>(self, res: Result<P, E>, f: F) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+    {
+        self.apply_if_some(res.ok(), f)
+    }
+
+    fn apply_if_ok_else<F, G, P, R, S, E>(self, res: Result<P, E>, f: F, on_err: G) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+        G: FnOnce(&mut Self, E) -> S,
+    {
+        let mut receiver = self;
+        match res {
+            Ok(p) => {
+                f(&mut receiver, p);
+            }
+            Err(e) => {
+                on_err(&mut receiver, e);
+            }
+        }
+        receiver
+    }
+
+    fn apply_when_debug<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.apply_if(cfg!(debug_assertions), f)
+    }
+
+    fn apply_while<P, F, R>(self, mut pred: P, mut f: F) -> Self
+    where
+        P: FnMut(&Self) -> bool,
+        F: FnMut(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        while pred(&receiver) {
+            f(&mut receiver);
+        }
+        receiver
+    }
+
+    fn apply_until_fixpoint<F, R>(self, mut f: F) -> Self
+    where
+        Self: Clone + PartialEq,
+        F: FnMut(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        loop {
+            let previous = receiver.clone();
+            f(&mut receiver);
+            if receiver == previous {
+                return receiver;
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn apply_spawn<F, R>(self, f: F) -> std::thread::JoinHandle<Self>
+    where
+        Self: Send + 'static,
+        F: FnOnce(&mut Self) -> R + Send + 'static,
+    {
+        std::thread::spawn(move || self.apply(f))
+    }
+
+    #[cfg(feature = "std")]
+    fn apply_from_channel(self, rx: std::sync::mpsc::Receiver<ApplyFn<Self>>) -> Self {
+        let mut receiver = self;
+        for f in rx {
+            f(&mut receiver);
+        }
+        receiver
+    }
+
+    fn seal(self) -> Frozen<Self> {
+        Frozen::new(self)
+    }
+
+    fn apply_take<F>(&mut self, f: F) -> &mut Self
+    where
+        Self: Default,
+        F: FnOnce(Self) -> Self,
+    {
+        *self = f(core::mem::take(self));
+        self
+    }
+
+    fn apply_owned<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        f(self)
+    }
+
+    fn apply_and<F, R>(self, f: F) -> (Self, R)
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        let result = f(&mut receiver);
+        (receiver, result)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn apply_with_params_collect<F, P, I, R>(self, f: F, p: I) -> (Self, Vec<R>)
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        let results = p.into_iter().map(|param| f(&mut receiver, param)).collect();
+        (receiver, results)
+    }
+
+    fn apply_with_args<F, A, R>(self, f: F, args: A) -> Self
+    where
+        A: ApplyArgs<Self, F, R>,
+    {
+        let mut receiver = self;
+        args.apply_to(&mut receiver, f);
+        receiver
+    }
+
+    fn apply_with_params_ref<F, P, R>(self, f: F, p: &[P]) -> Self
+    where
+        F: Fn(&mut Self, &P) -> R,
+    {
+        let mut receiver = self;
+        for param in p {
+            f(&mut receiver, param);
+        }
+        receiver
+    }
+
+    fn apply_with_param_pairs<F, A, B, I, R>(self, f: F, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (A, B)>,
+        F: Fn(&mut Self, A, B) -> R,
+    {
+        let mut receiver = self;
+        for (a, b) in pairs {
+            f(&mut receiver, a, b);
+        }
+        receiver
+    }
+
+    fn apply_zipped<FS, F, PS, P, R>(self, fs: FS, ps: PS) -> Self
+    where
+        FS: IntoIterator<Item = F>,
+        F: FnOnce(&mut Self, P) -> R,
+        PS: IntoIterator<Item = P>,
+    {
+        let mut receiver = self;
+        for (f, param) in fs.into_iter().zip(ps) {
+            f(&mut receiver, param);
+        }
+        receiver
+    }
+
+    fn apply_all<FS, F, R>(self, fs: FS) -> Self
+    where
+        FS: IntoIterator<Item = F>,
+        F: FnOnce(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        for f in fs {
+            f(&mut receiver);
+        }
+        receiver
+    }
+
+    #[cfg(feature = "alloc")]
+    fn apply_batch(self, ops: Vec<LocalApplyFn<'_, Self>>) -> Self {
+        let mut receiver = self;
+        for f in ops {
+            f(&mut receiver);
+        }
+        receiver
+    }
+
+    fn apply_with_params_enumerated<F, P, I, R>(self, f: F, p: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, usize, P) -> R,
+    {
+        let mut receiver = self;
+        for (index, param) in p.into_iter().enumerate() {
+            f(&mut receiver, index, param);
+        }
+        receiver
+    }
+
+    fn apply_fold<F, A>(self, init: A, f: F) -> (Self, A)
+    where
+        F: FnOnce(&mut Self, A) -> A,
+    {
+        let mut receiver = self;
+        let acc = f(&mut receiver, init);
+        (receiver, acc)
+    }
+
+    fn apply_with_env<E, F, R>(self, env: &E, f: F) -> Self
+    where
+        E: ?Sized,
+        F: FnOnce(&mut Self, &E) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver, env);
+        receiver
+    }
+
+    fn apply_with<U, F, R>(self, other: &U, f: F) -> Self
+    where
+        U: ?Sized,
+        F: FnOnce(&mut Self, &U) -> R,
+    {
+        self.apply_with_env(other, f)
+    }
+
+    fn apply_n<F, R>(self, times: usize, mut f: F) -> Self
+    where
+        F: FnMut(&mut Self, usize) -> R,
+    {
+        let mut receiver = self;
+        for index in 0..times {
+            f(&mut receiver, index);
+        }
+        receiver
+    }
+
+    fn apply_with_params_flow<F, P, I, B>(self, f: F, p: I) -> (Self, Option<B>)
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> ControlFlow<B>,
+    {
+        let mut receiver = self;
+        for param in p {
+            if let ControlFlow::Break(value) = f(&mut receiver, param) {
+                return (receiver, Some(value));
+            }
+        }
+        (receiver, None)
+    }
+
+    fn apply_keyed_params<F, K, V, I, R>(self, f: F, map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: Fn(&mut Self, K, V) -> R,
+    {
+        self.apply_with_param_pairs(f, map)
+    }
+
+    fn apply_chunked_params<F, P, R>(self, f: F, p: &[P], chunk_size: usize) -> Self
+    where
+        F: Fn(&mut Self, &[P]) -> R,
+    {
+        let mut receiver = self;
+        for chunk in p.chunks(chunk_size) {
+            f(&mut receiver, chunk);
+        }
+        receiver
+    }
+
+    fn apply_with_progress<F, P, I, R, G>(self, f: F, p: I, mut progress: G) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: ExactSizeIterator,
+        F: Fn(&mut Self, P) -> R,
+        G: FnMut(usize, usize),
+    {
+        let mut receiver = self;
+        let params = p.into_iter();
+        let total = params.len();
+        for (index, param) in params.enumerate() {
+            f(&mut receiver, param);
+            progress(index, total);
+        }
+        receiver
+    }
+
+    fn apply_with_generated_params<G, F, P, R>(self, n: usize, mut gen: G, f: F) -> Self
+    where
+        G: FnMut(usize) -> P,
+        F: Fn(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        for i in 0..n {
+            f(&mut receiver, gen(i));
+        }
+        receiver
+    }
+
+    fn apply_with_param_into<F, P, Q, R>(self, f: F, p: P) -> Self
+    where
+        P: Into<Q>,
+        F: FnOnce(&mut Self, Q) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p.into());
+        receiver
+    }
+
+    fn apply_with_borrowed_param<F, P, Q, R>(self, f: F, p: P) -> Self
+    where
+        Q: ?Sized,
+        P: Borrow<Q>,
+        F: FnOnce(&mut Self, &Q) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p.borrow());
+        receiver
+    }
+
+    fn apply_with_opt_param<F, P, R>(self, f: F, p: Option<P>) -> Self
+    where
+        F: FnOnce(&mut Self, P) -> R,
+    {
+        self.apply_if_some(p, f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_apply_with_fallback_params<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+    ) -> Result<(Self, usize), Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        let mut errors = Vec::new();
+        for (index, param) in p.into_iter().enumerate() {
+            match f(&mut receiver, param) {
+                Ok(_) => return Ok((receiver, index)),
+                Err(error) => errors.push((index, error)),
+            }
+        }
+        Err(errors)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_apply_with_params_retry<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+        policy: RetryPolicy,
+    ) -> Result<Self, ApplyError<E>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Clone,
+        F: Fn(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        for (index, param) in p.into_iter().enumerate() {
+            policy
+                .retry(|| f(&mut receiver, param.clone()))
+                .map_err(|source| ApplyError::new(index, source))?;
+        }
+        Ok(receiver)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_apply_with_params_retry_collect<F, P, I, R, E>(
+        self,
+        f: F,
+        p: I,
+        policy: RetryPolicy,
+    ) -> Result<Self, Vec<(usize, E)>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Clone,
+        F: Fn(&mut Self, P) -> Result<R, E>,
+    {
+        let mut receiver = self;
+        let mut errors = Vec::new();
+        for (index, param) in p.into_iter().enumerate() {
+            if let Err(error) = policy.retry(|| f(&mut receiver, param.clone())) {
+                errors.push((index, error));
+            }
+        }
+        if errors.is_empty() {
+            Ok(receiver)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn pipe<F, U>(self, f: F) -> U
+    where
+        F: FnOnce(Self) -> U,
+    {
+        f(self)
+    }
+
+    fn run<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Self) -> R,
+    {
+        self.pipe(f)
+    }
+
+    fn try_into_applied<U>(self) -> Result<U, <Self as TryInto<U>>::Error>
+    where
+        Self: TryInto<U>,
+    {
+        self.try_into()
+    }
+
+    fn apply_then_try_into<F, R, U>(self, f: F) -> Result<U, <Self as TryInto<U>>::Error>
+    where
+        Self: TryInto<U>,
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.apply(f).try_into_applied()
+    }
+
+    fn take_if<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        if pred(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn take_unless<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        self.take_if(|it| !pred(it))
+    }
+
+    fn apply_into<F, R, U>(self, f: F) -> U
+    where
+        U: From<Self>,
+        F: FnOnce(&mut Self) -> R,
+    {
+        U::from(self.apply(f))
+    }
+
+    fn apply_ref<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        f(&self);
+        self
+    }
+
+    fn apply_strict<F, R>(self, f: F) -> Self
+    where
+        R: UnitReturn,
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.apply(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_apply() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).apply(|it| it.push("src/lib.rs"));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_twice() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply(|it| it.push("src"))
+            .apply(|it| it.push("lib.rs"));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_ohter_usage() {
+        #[derive(Debug, PartialEq)]
+        struct Dog {
+            name: String,
+            size: String,
+        }
+        impl Dog {
+            fn new() -> Self {
+                Self {
+                    name: "Pochi".to_string(),
+                    size: "Middle".to_string(),
+                }
+            }
+        }
+        let mut exact_dog = Dog::new();
+        exact_dog.size = "Big".to_string();
+        let dog = Dog::new().apply(|it| it.size = "Big".to_string());
+        assert_eq!(dog, exact_dog);
+    }
+
+    #[test]
+    fn test_apply_param() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).apply_with_param(PathBuf::push, "src/lib.rs");
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_params() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        exact_path.push("lib.rs");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply_with_params(PathBuf::push, vec!["src", "lib.rs"]);
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_params_iterator_case() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let names = ["zero", "one", "two"];
+        let map = HashMap::new().apply_with_params(|it, n| it.insert(n, names[n]), 1..3);
+        assert_eq!(map, exact_map);
+        let map = HashMap::new().apply_with_params(
+            |it, (n, name)| it.insert(n, *name),
+            names.iter().enumerate().skip(1),
+        );
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_non_unit_return_method_case() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .apply(|it| it.insert(2, "two"));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).try_apply(|it| -> Result<(), ()> {
+            it.push("src/lib.rs");
+            Ok(())
+        });
+        assert_eq!(path, Ok(exact_path));
+    }
+
+    #[test]
+    fn test_try_apply_error_case() {
+        let numbers = Vec::new()
+            .try_apply(|it| "1".parse::<i32>().map(|n| it.push(n)))
+            .and_then(|it| it.try_apply(|it| "two".parse::<i32>().map(|n| it.push(n))))
+            .and_then(|it| it.try_apply(|it| "3".parse::<i32>().map(|n| it.push(n))));
+        assert!(numbers.is_err());
+    }
+
+    #[test]
+    fn test_try_apply_param() {
+        use std::fmt::Write;
+        let text = String::from("src").try_apply_with_param(String::write_str, "/lib.rs");
+        assert_eq!(text, Ok("src/lib.rs".to_string()));
+    }
+
+    #[test]
+    fn test_try_apply_param_error_case() {
+        let numbers = Vec::<u8>::new().try_apply_with_param(Vec::try_reserve, usize::MAX);
+        assert!(numbers.is_err());
+    }
+
+    #[test]
+    fn test_try_apply_params() {
+        let numbers = Vec::new().try_apply_with_params(
+            |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+            vec!["1", "2", "3"],
+        );
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_try_apply_params_error_case() {
+        let count = std::cell::Cell::new(0);
+        let numbers = Vec::new().try_apply_with_params(
+            |it, s: &str| {
+                count.set(count.get() + 1);
+                s.parse::<i32>().map(|n| it.push(n))
+            },
+            vec!["1", "two", "three"],
+        );
+        let error = numbers.unwrap_err();
+        assert_eq!(error.index, 1);
+        assert_eq!(error.source, "two".parse::<i32>().unwrap_err());
+        assert_eq!(count.get(), 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_apply_params_collect() {
+        let numbers = Vec::new().try_apply_with_params_collect(
+            |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+            vec!["1", "2", "3"],
+        );
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_apply_params_collect_error_case() {
+        let count = std::cell::Cell::new(0);
+        let numbers = Vec::new().try_apply_with_params_collect(
+            |it, s: &str| {
+                count.set(count.get() + 1);
+                s.parse::<i32>().map(|n| it.push(n))
+            },
+            vec!["one", "2", "three"],
+        );
+        let errors = numbers.unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, 0);
+        assert_eq!(errors[1].0, 2);
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn test_try_apply_or() {
+        let numbers = Vec::new()
+            .try_apply_or(|it| "1".parse::<i32>().map(|n| it.push(n)), |it| it.push(0))
+            .try_apply_or(
+                |it| "two".parse::<i32>().map(|n| it.push(n)),
+                |it| it.push(0),
+            );
+        assert_eq!(numbers, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_try_apply_or_else() {
+        let numbers = Vec::new()
+            .try_apply_or_else(
+                |it| "1".parse::<i32>().map(|n| it.push(n)),
+                |it, _| it.push(0),
+            )
+            .try_apply_or_else(
+                |it| "-2".parse::<u32>().map(|n| it.push(n as i32)),
+                |it, e| it.push(e.to_string().len() as i32),
+            );
+        assert_eq!(numbers, vec![1, 29]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_apply_context() {
+        let numbers = Vec::new()
+            .try_apply_context("first", |it| "1".parse::<i32>().map(|n| it.push(n)))
+            .and_then(|it| it.try_apply_context(2, |it| "2".parse::<i32>().map(|n| it.push(n))));
+        assert_eq!(numbers, Ok(vec![1, 2]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_apply_context_error_case() {
+        let numbers = Vec::<i32>::new()
+            .try_apply_context(format!("step {}", 1), |it| it.try_reserve(usize::MAX));
+        let error = numbers.unwrap_err();
+        assert_eq!(error.context, "step 1");
+        assert!(error.to_string().starts_with("step 1: "));
+    }
+
+    #[test]
+    fn test_try_apply_transactional() {
+        let numbers = vec![1].try_apply_transactional(|it| {
+            it.push(2);
+            "3".parse::<i32>().map(|n| it.push(n))
+        });
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_try_apply_transactional_error_case() {
+        let numbers = vec![1]
+            .try_apply_transactional(|it| {
+                it.push(2);
+                "three".parse::<i32>().map(|n| it.push(n))
+            })
+            .unwrap_or_else(RollbackError::into_value)
+            .apply(|it| it.push(4));
+        assert_eq!(numbers, vec![1, 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_apply_retry() {
+        let mut attempts = 0;
+        let numbers = Vec::new().try_apply_retry(
+            |it| {
+                attempts += 1;
+                if attempts < 3 {
+                    Err(attempts)
+                } else {
+                    it.push(attempts);
+                    Ok(())
+                }
+            },
+            RetryPolicy::immediate(5),
+        );
+        assert_eq!(numbers, Ok(vec![3]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_apply_retry_error_case() {
+        let mut attempts = 0;
+        let numbers = Vec::<i32>::new().try_apply_retry(
+            |_| {
+                attempts += 1;
+                Err::<(), _>(attempts)
+            },
+            RetryPolicy::exponential(3, std::time::Duration::from_millis(1)),
+        );
+        assert_eq!(numbers, Err(3));
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn test_try_apply_opt() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "zero"))
+            .try_apply_opt(|it| it.insert(1, "one"));
+        assert_eq!(map, Some(exact_map));
+    }
+
+    #[test]
+    fn test_try_apply_opt_none_case() {
+        let numbers = vec![1, 2]
+            .try_apply_opt(|it| it.pop())
+            .and_then(|it| it.try_apply_opt(|it| it.pop()))
+            .and_then(|it| it.try_apply_opt(|it| it.pop()));
+        assert_eq!(numbers, None);
+    }
+
+    #[test]
+    fn test_apply_if() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply_if(true, |it| it.push("src"))
+            .apply_if(false, |it| it.push("lib.rs"));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_if_else() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply_if_else(true, |it| it.push("src"), |it| it.push("tests"))
+            .apply_if_else(false, |it| it.push("main.rs"), |it| it.push("lib.rs"));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_unless() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply_unless(false, |it| it.push("src"))
+            .apply_unless(true, |it| it.push("lib.rs"));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_when() {
+        let path = PathBuf::from("src/lib.rs")
+            .apply_when(|it| it.extension().is_none(), |it| it.set_extension("txt"));
+        assert_eq!(path, PathBuf::from("src/lib.rs"));
+        let path = PathBuf::from("src/lib")
+            .apply_when(|it| it.extension().is_none(), |it| it.set_extension("rs"));
+        assert_eq!(path, PathBuf::from("src/lib.rs"));
+    }
+
+    #[test]
+    fn test_apply_if_some() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::new()
+            .apply_if_some(Some((1, "one")), |it, (k, v)| it.insert(k, v))
+            .apply_if_some(None, |it, (k, v)| it.insert(k, v));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_if_ok() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .apply_if_ok(Ok::<_, ()>("src"), |it, p| it.push(p))
+            .apply_if_ok(Err(()), |it, p: &str| it.push(p));
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_if_ok_else() {
+        let messages = Vec::new()
+            .apply_if_ok_else(
+                Ok::<_, String>("ok".to_string()),
+                |it, s| it.push(s),
+                |it, e| it.push(format!("error: {}", e)),
+            )
+            .apply_if_ok_else(
+                Err("failed".to_string()),
+                |it, s| it.push(s),
+                |it, e| it.push(format!("error: {}", e)),
+            );
+        assert_eq!(messages, vec!["ok", "error: failed"]);
+    }
+
+    #[test]
+    fn test_apply_when_debug() {
+        let numbers = vec![1].apply_when_debug(|it| it.push(2));
+        if cfg!(debug_assertions) {
+            assert_eq!(numbers, vec![1, 2]);
+        } else {
+            assert_eq!(numbers, vec![1]);
+        }
+    }
+
+    #[test]
+    fn test_apply_while() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src");
+        let path = exact_path
+            .clone()
+            .apply(|it| it.push("a/b/c"))
+            .apply_while(|it| !it.ends_with("src"), |it| it.pop());
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_while_false_at_first() {
+        let numbers = vec![1].apply_while(|it| it.is_empty(), |it| it.push(0));
+        assert_eq!(numbers, vec![1]);
+    }
+
+    #[test]
+    fn test_apply_until_fixpoint() {
+        let mut count = 0;
+        let number = 1000.apply_until_fixpoint(|it| {
+            count += 1;
+            *it /= 10;
+        });
+        assert_eq!(number, 0);
+        assert_eq!(count, 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_apply_spawn() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let main_thread = std::thread::current().id();
+        let (path, thread) = (PathBuf::from(env!("CARGO_MANIFEST_DIR")), None)
+            .apply_spawn(|it| {
+                it.0.push("src/lib.rs");
+                it.1 = Some(std::thread::current().id());
+            })
+            .join()
+            .unwrap();
+        assert_eq!(path, exact_path);
+        assert_ne!(thread, Some(main_thread));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_apply_from_channel() {
+        use std::sync::mpsc;
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let (sender, receiver) = mpsc::channel::<ApplyFn<HashMap<_, _>>>();
+        let worker = std::thread::spawn(move || {
+            sender
+                .send(Box::new(|it| {
+                    it.insert(1, "one");
+                }))
+                .unwrap();
+            sender
+                .send(Box::new(|it| {
+                    it.insert(2, "two");
+                }))
+                .unwrap();
+        });
+        let map = HashMap::new().apply_from_channel(receiver);
+        worker.join().unwrap();
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_seal() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::new().apply(|it| it.insert(1, "one")).seal();
+        assert_eq!(*map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_take() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut maps = vec![HashMap::new()];
+        maps[0]
+            .apply_take(|it| it.apply(|it| it.insert(1, "one")))
+            .apply_take(|it| it.apply(|it| it.insert(2, "two")));
+        assert_eq!(maps, vec![exact_map]);
+    }
+
+    #[test]
+    fn test_apply_owned() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(2, "two");
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .apply_owned(|it| it.into_iter().map(|(k, v)| (k * 2, v)).collect())
+            .apply(|it| it.insert(2, "two"));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_and() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let (map, previous) = HashMap::new()
+            .apply(|it| it.insert(1, "uno"))
+            .apply_and(|it| it.insert(1, "one"));
+        assert_eq!(map, exact_map);
+        assert_eq!(previous, Some("uno"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_apply_with_params_collect() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let (map, previous) = HashMap::new().apply_with_params_collect(
+            |it, (k, v)| it.insert(k, v),
+            vec![(1, "uno"), (2, "two"), (1, "one")],
+        );
+        assert_eq!(map, exact_map);
+        assert_eq!(previous, vec![None, None, Some("uno")]);
+    }
+
+    #[test]
+    fn test_apply_with_args() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = HashMap::new()
+            .apply_with_args(HashMap::insert, (1, "one"))
+            .apply_with_args(
+                |it: &mut HashMap<_, _>, k, v, _| it.insert(k, v),
+                (2, "two", ()),
+            );
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_with_params_ref() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one".to_string());
+        exact_map.insert(2, "two".to_string());
+        let params = [(1, "one".to_string()), (2, "two".to_string())];
+        let map =
+            HashMap::new().apply_with_params_ref(|it, (k, v)| it.insert(*k, v.clone()), &params);
+        assert_eq!(map, exact_map);
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn test_apply_with_param_pairs() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = HashMap::new().apply_with_param_pairs(HashMap::insert, [(1, "one"), (2, "two")]);
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_zipped() {
+        let steps: [fn(&mut Vec<i32>, i32); 3] =
+            [Vec::push, |it, n| it.retain(|x| *x != n), Vec::push];
+        let numbers = vec![1].apply_zipped(steps, vec![2, 1, 3, 4]);
+        assert_eq!(numbers, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_apply_all() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let steps: [fn(&mut HashMap<i32, &str>); 2] = [
+            |it| {
+                it.insert(1, "one");
+            },
+            |it| {
+                it.insert(2, "two");
+            },
+        ];
+        let map = HashMap::new().apply_all(steps);
+        assert_eq!(map, exact_map);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_apply_batch() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let values = ["one", "two"];
+        let ops = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| -> LocalApplyFn<HashMap<_, _>> {
+                Box::new(move |it| {
+                    it.insert(i + 1, *v);
+                })
+            })
+            .collect();
+        let map = HashMap::new().apply_batch(ops);
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_with_params_enumerated() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(0, "zero");
+        exact_map.insert(1, "one");
+        let map = HashMap::new().apply_with_params_enumerated(HashMap::insert, vec!["zero", "one"]);
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_fold() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let (map, replaced) = HashMap::new()
+            .apply(|it| it.insert(1, "uno"))
+            .apply_fold(0, |it, n| n + it.insert(1, "one").map_or(0, |_| 1));
+        let (map, replaced) =
+            map.apply_fold(replaced, |it, n| n + it.insert(2, "two").map_or(0, |_| 1));
+        assert_eq!(map, exact_map);
+        assert_eq!(replaced, 1);
+    }
+
+    #[test]
+    fn test_apply_with_env() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let names: &[&str] = &["zero", "one", "two"];
+        let map = HashMap::new()
+            .apply_with_env(names, |it, names| it.insert(1, names[1]))
+            .apply_with_env(names, |it, names| it.insert(2, names[2]));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_with() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let other = HashMap::new().apply(|it| it.insert(2, "two"));
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .apply_with(&other, |it, other| {
+                it.extend(other.iter().map(|(k, v)| (*k, *v)))
+            });
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_n() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(0, "default");
+        exact_map.insert(1, "default");
+        let map = HashMap::new().apply_n(2, |it, i| it.insert(i, "default"));
+        assert_eq!(map, exact_map);
+        let numbers = vec![1].apply_n(0, |it, _| it.clear());
+        assert_eq!(numbers, vec![1]);
+    }
+
+    #[test]
+    fn test_apply_with_params_flow() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let count = std::cell::Cell::new(0);
+        let (map, stopped) = HashMap::new().apply_with_params_flow(
+            |it, (k, v)| {
+                count.set(count.get() + 1);
+                if v == "stop" {
+                    return ControlFlow::Break(k);
+                }
+                it.insert(k, v);
+                ControlFlow::Continue(())
+            },
+            vec![(1, "one"), (2, "stop"), (3, "three")],
+        );
+        assert_eq!(map, exact_map);
+        assert_eq!(stopped, Some(2));
+        assert_eq!(count.get(), 2);
+
+        let (numbers, stopped) = vec![1].apply_with_params_flow(
+            |it, n| {
+                it.push(n);
+                ControlFlow::<()>::Continue(())
+            },
+            2..4,
+        );
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(stopped, None);
+    }
+
+    #[test]
+    fn test_apply_keyed_params() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "uno");
+        exact_map.insert(2, "two");
+        exact_map.insert(3, "three");
+        let map = HashMap::new()
+            .apply_keyed_params(HashMap::insert, vec![(1, "one"), (2, "two")])
+            .apply_keyed_params(HashMap::insert, vec![(1, "uno"), (3, "three")]);
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_chunked_params() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        exact_map.insert(3, "three");
+        let sizes = std::cell::RefCell::new(Vec::new());
+        let map = HashMap::new().apply_chunked_params(
+            |it, chunk| {
+                sizes.borrow_mut().push(chunk.len());
+                it.extend(chunk.iter().copied());
+            },
+            &[(1, "one"), (2, "two"), (3, "three")],
+            2,
+        );
+        assert_eq!(map, exact_map);
+        assert_eq!(sizes.into_inner(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_apply_with_progress() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut progress = Vec::new();
+        let map = HashMap::new().apply_with_progress(
+            |it, (k, v)| it.insert(k, v),
+            vec![(1, "one"), (2, "two")],
+            |index, total| progress.push((index, total)),
+        );
+        assert_eq!(map, exact_map);
+        assert_eq!(progress, vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn test_apply_with_generated_params() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(0, "even");
+        exact_map.insert(1, "odd");
+        exact_map.insert(2, "even");
+        let map = HashMap::new().apply_with_generated_params(
+            3,
+            |i| (i, if i % 2 == 0 { "even" } else { "odd" }),
+            |it, (k, v)| it.insert(k, v),
+        );
+        assert_eq!(map, exact_map);
+        assert_eq!(
+            Vec::<usize>::new().apply_with_generated_params(0, |i| i, Vec::push),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_apply_with_param_into() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1u64, "one".to_string());
+        let map = HashMap::new().apply_with_param_into(
+            |it: &mut HashMap<u64, String>, k: u64| it.insert(k, "one".to_string()),
+            1u8,
+        );
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_with_borrowed_param() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert("two".to_string(), 2);
+        let map = HashMap::new()
+            .apply(|it| it.insert("one".to_string(), 1))
+            .apply(|it| it.insert("two".to_string(), 2))
+            .apply_with_borrowed_param(HashMap::<String, i32>::remove::<str>, "one".to_string());
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_with_opt_param() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::new()
+            .apply_with_opt_param(|it, (k, v)| it.insert(k, v), Some((1, "one")))
+            .apply_with_opt_param(|it, (k, v)| it.insert(k, v), None);
+        assert_eq!(map, exact_map);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_apply_with_fallback_params() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "two");
+        let insert_parsed = |it: &mut HashMap<i32, &str>, (k, v): (&str, &'static str)| {
+            k.parse::<i32>().map(|k| {
+                it.insert(k, v);
+            })
+        };
+        let map = HashMap::new().try_apply_with_fallback_params(
+            insert_parsed,
+            vec![("one", "one"), ("1", "two"), ("3", "three")],
+        );
+        assert_eq!(map, Ok((exact_map, 1)));
+
+        let map = HashMap::new()
+            .try_apply_with_fallback_params(insert_parsed, vec![("one", "one"), ("two", "two")]);
+        let indices: Vec<usize> = map.unwrap_err().into_iter().map(|(i, _)| i).collect();
+        assert_eq!(indices, vec![0, 1]);
+        let map = HashMap::new().try_apply_with_fallback_params(insert_parsed, Vec::new());
+        assert!(map.unwrap_err().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_apply_with_params_retry() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let attempts = std::cell::Cell::new(0);
+        let insert_flaky = |it: &mut HashMap<i32, &'static str>, (k, v): (i32, &'static str)| {
+            attempts.set(attempts.get() + 1);
+            if k == 2 && attempts.get() < 4 {
+                return Err(k);
+            }
+            it.insert(k, v);
+            Ok(())
+        };
+        let map = HashMap::new().try_apply_with_params_retry(
+            insert_flaky,
+            vec![(1, "one"), (2, "two")],
+            RetryPolicy::immediate(3),
+        );
+        assert_eq!(map, Ok(exact_map));
+        assert_eq!(attempts.get(), 4);
+
+        attempts.set(0);
+        let map = HashMap::new().try_apply_with_params_retry(
+            insert_flaky,
+            vec![(1, "one"), (2, "two"), (3, "three")],
+            RetryPolicy::immediate(2),
+        );
+        assert_eq!(map, Err(ApplyError::new(1, 2)));
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_apply_with_params_retry_collect() {
+        let attempts = std::cell::Cell::new(0);
+        let numbers = Vec::new().try_apply_with_params_retry_collect(
+            |it, s: &str| {
+                attempts.set(attempts.get() + 1);
+                s.parse::<i32>().map(|n| it.push(n))
+            },
+            vec!["1", "two", "3", "four"],
+            RetryPolicy::immediate(2),
+        );
+        let indices: Vec<usize> = numbers.unwrap_err().into_iter().map(|(i, _)| i).collect();
+        assert_eq!(indices, vec![1, 3]);
+        assert_eq!(attempts.get(), 6);
+        let numbers = Vec::new().try_apply_with_params_retry_collect(
+            |it, s: &str| s.parse::<i32>().map(|n| it.push(n)),
+            vec!["1", "2", "3"],
+            RetryPolicy::immediate(2),
+        );
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_pipe() {
+        let keys = HashMap::new()
+            .apply(|it| it.insert(2, "two"))
+            .apply(|it| it.insert(1, "one"))
+            .pipe(|it| it.into_keys().collect::<Vec<_>>())
+            .apply(|it| it.sort());
+        assert_eq!(keys, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_run() {
+        let total = HashMap::new()
+            .apply(|it| it.insert("one", 1))
+            .apply(|it| it.insert("two", 2))
+            .run(|it| it.values().sum::<i32>());
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn test_try_into_applied() {
+        let port = 8000u32.apply(|it| *it += 80).try_into_applied::<u16>();
+        assert_eq!(port, Ok(8080));
+        let port = 8000u32.apply(|it| *it *= 10).try_into_applied::<u16>();
+        assert!(port.is_err());
+    }
+
+    #[test]
+    fn test_apply_then_try_into() {
+        let pair: Result<[i32; 2], _> = Vec::new().apply_then_try_into(|it| it.push(1));
+        assert_eq!(pair, Err(vec![1]));
+        let pair: Result<[i32; 2], _> = vec![1].apply_then_try_into(|it| it.push(2));
+        assert_eq!(pair, Ok([1, 2]));
+    }
+
+    #[test]
+    fn test_take_if() {
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .take_if(|it| it.contains_key(&1));
+        assert_eq!(map.map(|it| it.len()), Some(1));
+        let map = HashMap::<i32, &str>::new().take_if(|it| it.contains_key(&1));
+        assert_eq!(map, None);
+    }
+
+    #[test]
+    fn test_take_unless() {
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .take_unless(HashMap::is_empty);
+        assert_eq!(map.map(|it| it.len()), Some(1));
+        let map = HashMap::<i32, &str>::new().take_unless(HashMap::is_empty);
+        assert_eq!(map, None);
+    }
+
+    #[test]
+    fn test_apply_into() {
+        let queue: std::collections::VecDeque<_> = vec![1].apply_into(|it| it.push(2));
+        assert_eq!(queue, vec![1, 2]);
+        let map: HashMap<i32, &str> = [(1, "one")].apply_into(|it| it[0].1 = "uno");
+        assert_eq!(map.get(&1), Some(&"uno"));
+    }
+
+    #[test]
+    fn test_apply_ref() {
+        let mut sizes = Vec::new();
+        let map = HashMap::new()
+            .apply(|it| it.insert(1, "one"))
+            .apply_ref(|it| sizes.push(it.len()))
+            .apply_ref(HashMap::len);
+        assert_eq!(map.get(&1), Some(&"one"));
+        assert_eq!(sizes, vec![1]);
+    }
+
+    #[test]
+    fn test_apply_strict() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = HashMap::new().apply_strict(|it| {
+            it.insert(1, "one");
+        });
         assert_eq!(map, exact_map);
+        let names = Vec::new().apply_strict(|it| it.push("Pochi"));
+        assert_eq!(names, vec!["Pochi"]);
     }
 }
@@ -0,0 +1,153 @@
+use std::sync::{Mutex, OnceLock, PoisonError, RwLock};
+
+/// Allows you to apply any function given as a parameter to the value inside a lock.
+///
+/// The lock is acquired, the function is applied to the inner value, and the lock is released,
+/// so updates of shared state can be chained.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::sync::Mutex;
+/// let names = Mutex::new(Vec::new());
+/// names
+///     .apply_locked(|it| it.push("Pochi"))
+///     .unwrap()
+///     .apply_locked(|it| it.push("Hachi"))
+///     .unwrap();
+/// assert_eq!(*names.lock().unwrap(), vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub trait LockApplicable<T: ?Sized> {
+    /// Lock self, apply the function given as a parameter to the inner value, and release the
+    /// lock.
+    ///
+    /// Locks with separate read and write access are locked for writing. If the lock is
+    /// poisoned, the function is not applied and the error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::RwLock;
+    /// let count = RwLock::new(0);
+    /// count.apply_locked(|it| *it += 1).unwrap();
+    /// assert_eq!(*count.read().unwrap(), 1);
+    /// ```
+    fn apply_locked<F, R>(&self, f: F) -> Result<&Self, PoisonError<&Self>>
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T: ?Sized> LockApplicable<T> for Mutex<T> {
+    fn apply_locked<F, R>(&self, f: F) -> Result<&Self, PoisonError<&Self>>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = self.lock().map_err(|_| PoisonError::new(self))?;
+        f(&mut *guard);
+        Ok(self)
+    }
+}
+
+impl<T: ?Sized> LockApplicable<T> for RwLock<T> {
+    fn apply_locked<F, R>(&self, f: F) -> Result<&Self, PoisonError<&Self>>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = self.write().map_err(|_| PoisonError::new(self))?;
+        f(&mut *guard);
+        Ok(self)
+    }
+}
+
+/// Apply the function given as a parameter to the value of a global `OnceLock<Mutex<T>>`.
+///
+/// The cell is initialized with `T::default()` on first use, and then the function is applied
+/// under the lock in the same way as [`LockApplicable::apply_locked`]. For a
+/// `LazyLock<Mutex<T>>`, `apply_locked` can be called on the global directly.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::*;
+/// use std::sync::{Mutex, OnceLock};
+/// static NAMES: OnceLock<Mutex<Vec<&str>>> = OnceLock::new();
+/// apply_static(&NAMES, |it| it.push("Pochi")).unwrap();
+/// apply_static(&NAMES, |it| it.push("Hachi")).unwrap();
+/// assert_eq!(*NAMES.get().unwrap().lock().unwrap(), vec!["Pochi", "Hachi"]);
+/// ```
+pub fn apply_static<T, F, R>(
+    cell: &OnceLock<Mutex<T>>,
+    f: F,
+) -> Result<&Mutex<T>, PoisonError<&Mutex<T>>>
+where
+    T: Default,
+    F: FnOnce(&mut T) -> R,
+{
+    cell.get_or_init(|| Mutex::new(T::default()))
+        .apply_locked(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_apply_locked_mutex() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = Mutex::new(HashMap::new());
+        map.apply_locked(|it| it.insert(1, "one"))
+            .and_then(|it| it.apply_locked(|it| it.insert(2, "two")))
+            .unwrap();
+        assert_eq!(map.into_inner().unwrap(), exact_map);
+    }
+
+    #[test]
+    fn test_apply_locked_rwlock() {
+        let numbers = RwLock::new(vec![1]);
+        numbers.apply_locked(|it| it.push(2)).unwrap();
+        assert_eq!(numbers.into_inner().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_locked_poisoned_case() {
+        let numbers = Arc::new(Mutex::new(vec![1]));
+        let cloned = Arc::clone(&numbers);
+        let _ = std::thread::spawn(move || {
+            cloned.apply_locked(|_| panic!("poison the lock")).unwrap();
+        })
+        .join();
+        let result = numbers.apply_locked(|it| it.push(2));
+        assert!(result.is_err());
+        assert_eq!(
+            *numbers.lock().unwrap_or_else(PoisonError::into_inner),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_apply_static() {
+        static MAP: OnceLock<Mutex<HashMap<i32, &str>>> = OnceLock::new();
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        apply_static(&MAP, |it| it.insert(1, "one"))
+            .and_then(|it| it.apply_locked(|it| it.insert(2, "two")))
+            .unwrap();
+        assert_eq!(*MAP.get().unwrap().lock().unwrap(), exact_map);
+    }
+
+    #[test]
+    fn test_apply_locked_lazy_lock() {
+        static NUMBERS: std::sync::LazyLock<Mutex<Vec<i32>>> =
+            std::sync::LazyLock::new(|| Mutex::new(vec![1]));
+        NUMBERS.apply_locked(|it| it.push(2)).unwrap();
+        assert_eq!(*NUMBERS.lock().unwrap(), vec![1, 2]);
+    }
+}
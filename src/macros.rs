@@ -0,0 +1,121 @@
+/// Apply the function to the value only when the cfg predicate is true.
+///
+/// When the predicate is false, the step is removed at compile time entirely, so the function may
+/// refer to items which only exist on some platforms or with some features.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let targets = vec!["common"].apply(|it| it.push("all"));
+/// let targets = apply_cfg!(targets, cfg(unix), |it| it.push("unix"));
+/// let targets = apply_cfg!(targets, cfg(windows), |it| it.push("windows"));
+/// assert_eq!(targets.len(), if cfg!(any(unix, windows)) { 3 } else { 2 });
+/// ```
+///
+/// ```
+/// use apply_method::*;
+/// let args = apply_cfg!(vec!["run"], cfg(not(debug_assertions)), |it| it.push("--release"));
+/// assert_eq!(args.len(), if cfg!(debug_assertions) { 1 } else { 2 });
+/// ```
+#[macro_export]
+macro_rules! apply_cfg {
+    ($value:expr, cfg($($pred:tt)*), $f:expr $(,)?) => {{
+        let value = $value;
+        #[cfg($($pred)*)]
+        let value = $crate::Applicable::apply(value, $f);
+        value
+    }};
+}
+
+/// Apply the functions to the disjoint fields of the value concurrently, and return the value.
+///
+/// Each function receives the mutable reference to its own field and runs on a scoped thread, so
+/// expensive per-field initialization can run in parallel without any locks. The macro returns
+/// after all the functions have finished, and panics if any of them panicked.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// #[derive(Default)]
+/// struct Index {
+///     words: Vec<&'static str>,
+///     primes: Vec<u32>,
+/// }
+/// let index = apply_parallel!(Index::default(), {
+///     words: |it| it.extend("apply method".split(' ')),
+///     primes: |it| it.extend((2..20).filter(|n| (2..*n).all(|d| n % d != 0))),
+/// });
+/// assert_eq!(index.words, vec!["apply", "method"]);
+/// assert_eq!(index.primes, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! apply_parallel {
+    ($value:expr, { $($field:ident : $f:expr),+ $(,)? }) => {{
+        let mut value = $value;
+        ::std::thread::scope(|scope| {
+            $(scope.spawn({
+                let field = &mut value.$field;
+                move || $crate::__apply_to(field, $f)
+            });)+
+        });
+        value
+    }};
+}
+
+#[doc(hidden)]
+pub fn __apply_to<T: ?Sized, F, R>(value: &mut T, f: F)
+where
+    F: FnOnce(&mut T) -> R,
+{
+    f(value);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_apply_cfg() {
+        let numbers = apply_cfg!(vec![1], cfg(test), |it| it.push(2));
+        let numbers = apply_cfg!(numbers, cfg(not(test)), |it| it.push(3));
+        assert_eq!(numbers, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_cfg_removes_step() {
+        let numbers = apply_cfg!(vec![1], cfg(any()), |it| it.push(DoesNotExist));
+        assert_eq!(numbers, vec![1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_apply_parallel() {
+        use std::collections::HashMap;
+        struct Fields {
+            map: HashMap<i32, &'static str>,
+            numbers: Vec<i32>,
+            count: usize,
+        }
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let fields = Fields {
+            map: HashMap::new(),
+            numbers: vec![1],
+            count: 0,
+        };
+        let count = 2;
+        let fields = apply_parallel!(fields, {
+            map: |it| {
+                it.insert(1, "one");
+                it.insert(2, "two");
+            },
+            numbers: |it| it.push(2),
+            count: |it| *it += count,
+        });
+        assert_eq!(fields.map, exact_map);
+        assert_eq!(fields.numbers, vec![1, 2]);
+        assert_eq!(fields.count, 2);
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+/// Allows you to apply any function given as a parameter to the value behind a shared pointer
+/// with clone-on-write.
+///
+/// The value is cloned by `make_mut` only if the pointer is not unique, so the other owners keep
+/// seeing the original value.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::rc::Rc;
+/// let original = Rc::new(vec!["Pochi"]);
+/// let names = Rc::clone(&original).apply_make_mut(|it| it.push("Hachi"));
+/// assert_eq!(*original, vec!["Pochi"]);
+/// assert_eq!(*names, vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub trait MakeMutApplicable<T> {
+    /// Apply the function given as a parameter to the value behind self, cloning it first if
+    /// self is shared, and return self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::Arc;
+    /// let count = Arc::new(1).apply_make_mut(|it| *it += 1);
+    /// assert_eq!(*count, 2);
+    /// ```
+    fn apply_make_mut<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T: Clone> MakeMutApplicable<T> for Rc<T> {
+    fn apply_make_mut<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut receiver = self;
+        f(Rc::make_mut(&mut receiver));
+        receiver
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: Clone> MakeMutApplicable<T> for Arc<T> {
+    fn apply_make_mut<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut receiver = self;
+        f(Arc::make_mut(&mut receiver));
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_apply_make_mut_rc() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = Rc::new(HashMap::new())
+            .apply_make_mut(|it| it.insert(1, "one"))
+            .apply_make_mut(|it| it.insert(2, "two"));
+        assert_eq!(*map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_make_mut_arc_shared_case() {
+        let original = Arc::new(vec![1]);
+        let numbers = Arc::clone(&original).apply_make_mut(|it| it.push(2));
+        assert_eq!(*original, vec![1]);
+        assert_eq!(*numbers, vec![1, 2]);
+        assert!(!Arc::ptr_eq(&original, &numbers));
+    }
+}
@@ -0,0 +1,361 @@
+//! Asynchronous applies built on native async closures, without boxing any future.
+//!
+//! The [`AsyncApplicable`] trait in this module has the same methods as the boxed one at the
+//! crate root, but takes async closures and returns unboxed futures, so no allocation is needed
+//! per chained apply. Import it explicitly instead of the boxed one to use it.
+//!
+//! This module is also available without the `std` and `alloc` features, e.g. on embedded
+//! targets driven by embassy, where multiple parameters can be applied from arrays or any other
+//! iterator.
+//!
+//! # Examples
+//!
+//! ```
+//! use apply_method::native_async::AsyncApplicable;
+//! # futures::executor::block_on(async {
+//! async fn fetch_name() -> String {
+//!     "Pochi".to_string()
+//! }
+//! let names = Vec::new()
+//!     .apply_async(async |it: &mut Vec<String>| it.push(fetch_name().await))
+//!     .await;
+//! assert_eq!(names, vec!["Pochi".to_string()]);
+//! # });
+//! ```
+
+use core::future::Future;
+use core::task::Poll;
+
+/// Allows you to apply any async closure given as a parameter to the object without boxing.
+pub trait AsyncApplicable: Sized {
+    /// Apply the async closure given as a parameter to self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src/lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async(async |it: &mut PathBuf| it.push("src"))
+    ///     .await
+    ///     .apply_async(async |it: &mut PathBuf| it.push("lib.rs"))
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    ///
+    fn apply_async<F, R>(self, f: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&mut Self) -> R;
+
+    /// Apply the async closure with one parameter given as a parameter to self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src/lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async_with_param(async |it: &mut PathBuf, p| it.push(p), "src/lib.rs")
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    ///
+    fn apply_async_with_param<F, P, R>(self, f: F, p: P) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&mut Self, P) -> R;
+
+    /// Apply apply_async_with_param repeatedly to multiple parameters.
+    ///
+    /// The closure is awaited for each parameter in order before the next one is applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// use std::path::PathBuf;
+    /// # futures::executor::block_on(async {
+    /// let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    /// exact_path.push("src");
+    /// exact_path.push("lib.rs");
+    /// let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    ///     .apply_async_with_params(async |it: &mut PathBuf, p| it.push(p), vec!["src", "lib.rs"])
+    ///     .await;
+    /// assert_eq!(path, exact_path);
+    /// # });
+    /// ```
+    fn apply_async_with_params<F, P, I, R>(self, f: F, p: I) -> impl Future<Output = Self>
+    where
+        I: IntoIterator<Item = P>,
+        F: AsyncFn(&mut Self, P) -> R;
+
+    /// Apply the async closure repeatedly to every parameter in the slice.
+    ///
+    /// The closure receives a reference to each parameter, and is awaited for each parameter in
+    /// order before the next one is applied. This doesn't need any allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// # futures::executor::block_on(async {
+    /// let readings = [3u16, 5, 7];
+    /// let sum = 0u32
+    ///     .apply_async_with_slice(async |it: &mut u32, r: &u16| *it += u32::from(*r), &readings)
+    ///     .await;
+    /// assert_eq!(sum, 15);
+    /// # });
+    /// ```
+    fn apply_async_with_slice<F, P, R>(self, f: F, p: &[P]) -> impl Future<Output = Self>
+    where
+        F: AsyncFn(&mut Self, &P) -> R;
+
+    /// Apply the async closure repeatedly to every parameter in the array.
+    ///
+    /// The closure is awaited for each parameter in order before the next one is applied. This
+    /// doesn't need any allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// # futures::executor::block_on(async {
+    /// let sum = 0u32
+    ///     .apply_async_with_array(async |it: &mut u32, r| *it += r, [3, 5, 7])
+    ///     .await;
+    /// assert_eq!(sum, 15);
+    /// # });
+    /// ```
+    fn apply_async_with_array<F, P, R, const N: usize>(
+        self,
+        f: F,
+        p: [P; N],
+    ) -> impl Future<Output = Self>
+    where
+        F: AsyncFn(&mut Self, P) -> R;
+
+    /// Apply apply_async_with_param repeatedly to multiple parameters until the cancellation
+    /// future completes.
+    ///
+    /// The cancellation future is checked before each parameter, so the application is
+    /// interrupted only between parameters. Self is returned with the number of applied
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::native_async::AsyncApplicable;
+    /// # futures::executor::block_on(async {
+    /// let (numbers, applied) = Vec::new()
+    ///     .apply_async_with_params_until(
+    ///         async |it: &mut Vec<i32>, n| it.push(n),
+    ///         vec![1, 2, 3],
+    ///         std::future::pending::<()>(),
+    ///     )
+    ///     .await;
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert_eq!(applied, 3);
+    /// # });
+    /// ```
+    fn apply_async_with_params_until<F, P, I, R, C>(
+        self,
+        f: F,
+        p: I,
+        cancel: C,
+    ) -> impl Future<Output = (Self, usize)>
+    where
+        I: IntoIterator<Item = P>,
+        F: AsyncFn(&mut Self, P) -> R,
+        C: Future;
+}
+
+impl<T> AsyncApplicable for T {
+    async fn apply_async<F, R>(self, f: F) -> Self
+    where
+        F: AsyncFnOnce(&mut Self) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver).await;
+        receiver
+    }
+
+    async fn apply_async_with_param<F, P, R>(self, f: F, p: P) -> Self
+    where
+        F: AsyncFnOnce(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p).await;
+        receiver
+    }
+
+    async fn apply_async_with_params<F, P, I, R>(self, f: F, p: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        F: AsyncFn(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        for param in p {
+            f(&mut receiver, param).await;
+        }
+        receiver
+    }
+
+    async fn apply_async_with_slice<F, P, R>(self, f: F, p: &[P]) -> Self
+    where
+        F: AsyncFn(&mut Self, &P) -> R,
+    {
+        let mut receiver = self;
+        for param in p {
+            f(&mut receiver, param).await;
+        }
+        receiver
+    }
+
+    async fn apply_async_with_array<F, P, R, const N: usize>(self, f: F, p: [P; N]) -> Self
+    where
+        F: AsyncFn(&mut Self, P) -> R,
+    {
+        let mut receiver = self;
+        for param in IntoIterator::into_iter(p) {
+            f(&mut receiver, param).await;
+        }
+        receiver
+    }
+
+    async fn apply_async_with_params_until<F, P, I, R, C>(
+        self,
+        f: F,
+        p: I,
+        cancel: C,
+    ) -> (Self, usize)
+    where
+        I: IntoIterator<Item = P>,
+        F: AsyncFn(&mut Self, P) -> R,
+        C: Future,
+    {
+        let mut receiver = self;
+        let mut cancel = core::pin::pin!(cancel);
+        let mut applied = 0;
+        for param in p {
+            let cancelled =
+                core::future::poll_fn(|cx| Poll::Ready(cancel.as_mut().poll(cx).is_ready())).await;
+            if cancelled {
+                break;
+            }
+            f(&mut receiver, param).await;
+            applied += 1;
+        }
+        (receiver, applied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_apply_async() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async(async |it: &mut PathBuf| it.push("src/lib.rs")),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_async_non_send_case() {
+        let value = block_on(Rc::new(1).apply_async(async |it: &mut Rc<i32>| *it = Rc::new(2)));
+        assert_eq!(*value, 2);
+    }
+
+    #[test]
+    fn test_apply_async_send_case() {
+        fn assert_send<T: Send>(_: &T) {}
+        let future = vec![1].apply_async(async |it: &mut Vec<i32>| it.push(2));
+        assert_send(&future);
+        assert_eq!(block_on(future), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_async_param() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async_with_param(async |it: &mut PathBuf, p| it.push(p), "src/lib.rs"),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_async_params() {
+        use std::collections::HashMap;
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let map = block_on(HashMap::new().apply_async_with_params(
+            async |it: &mut HashMap<_, _>, (k, v)| it.insert(k, v),
+            vec![(1, "one"), (2, "two")],
+        ));
+        assert_eq!(map, exact_map);
+    }
+
+    #[test]
+    fn test_apply_async_with_slice() {
+        let paths = [PathBuf::from("src"), PathBuf::from("lib.rs")];
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async_with_slice(async |it: &mut PathBuf, p: &PathBuf| it.push(p), &paths),
+        );
+        assert_eq!(path, exact_path);
+        assert_eq!(paths.len(), 2);
+    }
+
+    #[test]
+    fn test_apply_async_with_array() {
+        let mut exact_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        exact_path.push("src/lib.rs");
+        let path = block_on(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .apply_async_with_array(async |it: &mut PathBuf, p| it.push(p), ["src", "lib.rs"]),
+        );
+        assert_eq!(path, exact_path);
+    }
+
+    #[test]
+    fn test_apply_async_params_until() {
+        use std::cell::Cell;
+        let cancelled = Cell::new(false);
+        let cancel = std::future::poll_fn(|_| {
+            if cancelled.get() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        });
+        let (numbers, applied) = block_on(vec![0].apply_async_with_params_until(
+            async |it: &mut Vec<i32>, n| {
+                it.push(n);
+                cancelled.set(n == 2);
+            },
+            vec![1, 2, 3, 4],
+            cancel,
+        ));
+        assert_eq!(numbers, vec![0, 1, 2]);
+        assert_eq!(applied, 2);
+    }
+}
@@ -0,0 +1,129 @@
+use parking_lot::{Mutex, MutexGuard, RwLock, RwLockWriteGuard};
+
+/// Allows you to apply any function given as a parameter to the value inside a lock which can't
+/// be poisoned.
+///
+/// This is the counterpart of [`LockApplicable`](crate::LockApplicable), which doesn't return
+/// any error because the locks never get poisoned.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use parking_lot::Mutex;
+/// let names = Mutex::new(Vec::new());
+/// names
+///     .apply_locked(|it| it.push("Pochi"))
+///     .apply_locked(|it| it.push("Hachi"));
+/// assert_eq!(*names.lock(), vec!["Pochi", "Hachi"]);
+/// ```
+///
+pub trait InfallibleLockApplicable<T: ?Sized> {
+    /// Lock self, apply the function given as a parameter to the inner value, and release the
+    /// lock.
+    ///
+    /// Locks with separate read and write access are locked for writing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use parking_lot::RwLock;
+    /// let count = RwLock::new(0);
+    /// count.apply_locked(|it| *it += 1);
+    /// assert_eq!(*count.read(), 1);
+    /// ```
+    fn apply_locked<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R;
+
+    /// Lock self, apply the function given as a parameter to the part of the inner value
+    /// selected by `map`, and release the lock.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use parking_lot::Mutex;
+    /// let dog = Mutex::new(("Pochi".to_string(), 3));
+    /// dog.apply_locked_mapped(|it| &mut it.1, |age| *age += 1);
+    /// assert_eq!(dog.lock().1, 4);
+    /// ```
+    fn apply_locked_mapped<M, U, F, R>(&self, map: M, f: F) -> &Self
+    where
+        M: FnOnce(&mut T) -> &mut U,
+        U: ?Sized,
+        F: FnOnce(&mut U) -> R;
+}
+
+impl<T: ?Sized> InfallibleLockApplicable<T> for Mutex<T> {
+    fn apply_locked<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *self.lock());
+        self
+    }
+
+    fn apply_locked_mapped<M, U, F, R>(&self, map: M, f: F) -> &Self
+    where
+        M: FnOnce(&mut T) -> &mut U,
+        U: ?Sized,
+        F: FnOnce(&mut U) -> R,
+    {
+        f(&mut *MutexGuard::map(self.lock(), map));
+        self
+    }
+}
+
+impl<T: ?Sized> InfallibleLockApplicable<T> for RwLock<T> {
+    fn apply_locked<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *self.write());
+        self
+    }
+
+    fn apply_locked_mapped<M, U, F, R>(&self, map: M, f: F) -> &Self
+    where
+        M: FnOnce(&mut T) -> &mut U,
+        U: ?Sized,
+        F: FnOnce(&mut U) -> R,
+    {
+        f(&mut *RwLockWriteGuard::map(self.write(), map));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_locked_parking_lot_mutex() {
+        let counter = Mutex::new(0);
+        counter
+            .apply_locked(|it| *it += 1)
+            .apply_locked(|it| *it *= 3);
+        assert_eq!(counter.into_inner(), 3);
+    }
+
+    #[test]
+    fn test_apply_locked_parking_lot_rwlock() {
+        let numbers = RwLock::new(vec![1]);
+        numbers.apply_locked(|it| it.push(2));
+        assert_eq!(numbers.into_inner(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_apply_locked_mapped() {
+        let pair = Mutex::new((vec![1], vec![2]));
+        pair.apply_locked_mapped(|it| &mut it.0, |it| it.push(3))
+            .apply_locked_mapped(|it| it.1.as_mut_slice(), |it| it[0] = 4);
+        assert_eq!(pair.into_inner(), (vec![1, 3], vec![4]));
+        let text = RwLock::new(String::from("pochi"));
+        text.apply_locked_mapped(|it| it.as_mut_str(), |it| it.make_ascii_uppercase());
+        assert_eq!(text.into_inner(), "POCHI");
+    }
+}
@@ -0,0 +1,94 @@
+use core::ops::DerefMut;
+use core::pin::Pin;
+
+/// Allows you to apply any function given as a parameter to the pinned value.
+///
+/// The function receives `Pin<&mut T>` instead of `&mut T`, so `!Unpin` types like futures and
+/// intrusive structures can be configured with the same chain without ever being moved. Only
+/// the safe access of `Pin` is exposed, e.g. pin projections and `get_mut` for `Unpin` types.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::future::Future;
+/// use std::task::{Context, Waker};
+/// let mut ready = false;
+/// let future = Box::pin(async { 1 }).apply_pinned(|it| {
+///     ready = it.poll(&mut Context::from_waker(Waker::noop())).is_ready();
+/// });
+/// assert!(ready);
+/// drop(future);
+/// ```
+///
+pub trait PinApplicable<T: ?Sized> {
+    /// Apply the function given as a parameter to the pinned target of self, and return self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::pin::Pin;
+    /// let mut name = String::from("Pochi");
+    /// Pin::new(&mut name).apply_pinned(|it| it.get_mut().push('!'));
+    /// assert_eq!(name, "Pochi!");
+    /// ```
+    fn apply_pinned<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(Pin<&mut T>) -> R;
+}
+
+impl<P> PinApplicable<P::Target> for Pin<P>
+where
+    P: DerefMut,
+{
+    fn apply_pinned<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(Pin<&mut P::Target>) -> R,
+    {
+        let mut receiver = self;
+        f(receiver.as_mut());
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::marker::PhantomPinned;
+    use std::collections::HashMap;
+
+    struct Node {
+        value: i32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Node {
+        fn set_value(self: Pin<&mut Self>, value: i32) {
+            // Safety: `value` is not structurally pinned.
+            unsafe { self.get_unchecked_mut().value = value };
+        }
+    }
+
+    #[test]
+    fn test_apply_pinned_box() {
+        let node = Box::pin(Node {
+            value: 0,
+            _pinned: PhantomPinned,
+        })
+        .apply_pinned(|it| it.set_value(1));
+        assert_eq!(node.value, 1);
+    }
+
+    #[test]
+    fn test_apply_pinned_ref() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut map = HashMap::new();
+        Pin::new(&mut map)
+            .apply_pinned(|it| it.get_mut().insert(1, "one"))
+            .apply_pinned(|it| it.get_mut().insert(2, "two"));
+        assert_eq!(map, exact_map);
+    }
+}
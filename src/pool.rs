@@ -0,0 +1,141 @@
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, PoisonError};
+
+/// The pool of reusable values.
+///
+/// [`Pool::acquire`] checks a value out of the pool, and the value goes back to the pool when
+/// the returned [`Pooled`] is dropped, so the value can be reset with `apply` and reused.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let pool = Pool::new(Vec::<u8>::new);
+/// {
+///     let buffer = pool.acquire().apply(|it| it.extend(b"Pochi"));
+///     assert_eq!(*buffer, b"Pochi");
+/// }
+/// let buffer = pool.acquire().apply(|it| it.clear());
+/// assert!(buffer.is_empty());
+/// assert!(buffer.capacity() >= 5);
+/// ```
+pub struct Pool<T> {
+    idle: Mutex<Vec<T>>,
+    create: Box<dyn Fn() -> T + Send + Sync>,
+}
+
+impl<T> Pool<T> {
+    /// Create a new empty pool which creates new values with the function given as a parameter.
+    pub fn new<F>(create: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            idle: Mutex::new(Vec::new()),
+            create: Box::new(create),
+        }
+    }
+
+    /// Check a value out of the pool, creating a new one if there is no idle value.
+    pub fn acquire(&self) -> Pooled<'_, T> {
+        let value = self.idle().pop().unwrap_or_else(|| (self.create)());
+        Pooled {
+            pool: self,
+            value: ManuallyDrop::new(value),
+        }
+    }
+
+    /// Return the number of the idle values in the pool.
+    pub fn idle_len(&self) -> usize {
+        self.idle().len()
+    }
+
+    fn idle(&self) -> std::sync::MutexGuard<'_, Vec<T>> {
+        // The values in the pool are still valid even if another thread has panicked.
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("idle_len", &self.idle_len())
+            .finish()
+    }
+}
+
+/// The value checked out of a [`Pool`], which goes back to the pool when dropped.
+pub struct Pooled<'a, T> {
+    pool: &'a Pool<T>,
+    value: ManuallyDrop<T>,
+}
+
+impl<T> Pooled<'_, T> {
+    /// Take the value out without returning it to the pool.
+    pub fn detach(self) -> T {
+        let mut receiver = ManuallyDrop::new(self);
+        // Safety: `receiver` is never dropped, so the value is taken only once.
+        unsafe { ManuallyDrop::take(&mut receiver.value) }
+    }
+}
+
+impl<T> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Pooled<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        // Safety: the value is never used again after it is taken here.
+        let value = unsafe { ManuallyDrop::take(&mut self.value) };
+        self.pool.idle().push(value);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Pooled<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pooled").field(&*self.value).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Applicable;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_pool_acquire() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(2, "two");
+        let pool = Pool::new(HashMap::new);
+        let map = pool.acquire().apply(|it| it.insert(1, "one"));
+        drop(map);
+        assert_eq!(pool.idle_len(), 1);
+        let map = pool.acquire().apply(|it| {
+            it.clear();
+            it.insert(2, "two");
+        });
+        assert_eq!(pool.idle_len(), 0);
+        assert_eq!(*map, exact_map);
+    }
+
+    #[test]
+    fn test_pooled_detach() {
+        let pool = Pool::new(|| vec![1]);
+        let numbers = pool.acquire().apply(|it| it.push(2)).detach();
+        assert_eq!(numbers, vec![1, 2]);
+        assert_eq!(pool.idle_len(), 0);
+    }
+}
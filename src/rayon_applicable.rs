@@ -0,0 +1,147 @@
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+/// Allows you to apply any function given as a parameter to every element of the collection in
+/// parallel.
+///
+/// The elements are processed on the rayon thread pool, and the collection itself is returned, so
+/// data-parallel mutation can still be chained. For maps, the function is applied to the values.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let numbers = (1..=4)
+///     .collect::<Vec<i32>>()
+///     .par_apply_each(|it| *it *= 10)
+///     .apply(|it| it.push(50));
+/// assert_eq!(numbers, vec![10, 20, 30, 40, 50]);
+/// ```
+///
+pub trait ParApplicable: Sized {
+    /// The type of the elements which the function is applied to.
+    type Item;
+
+    /// Apply the function given as a parameter to every element of self in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::collections::HashMap;
+    /// let mut scores = HashMap::new();
+    /// scores.insert("Pochi", 1);
+    /// scores.insert("Hachi", 2);
+    /// let scores = scores.par_apply_each(|it| *it += 1);
+    /// assert_eq!(scores["Pochi"], 2);
+    /// assert_eq!(scores["Hachi"], 3);
+    /// ```
+    fn par_apply_each<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut Self::Item) -> R + Sync + Send;
+}
+
+impl<T: Send> ParApplicable for Vec<T> {
+    type Item = T;
+
+    fn par_apply_each<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut T) -> R + Sync + Send,
+    {
+        let mut receiver = self;
+        receiver.par_iter_mut().for_each(|it| {
+            f(it);
+        });
+        receiver
+    }
+}
+
+impl<T: Send> ParApplicable for &mut [T] {
+    type Item = T;
+
+    fn par_apply_each<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut T) -> R + Sync + Send,
+    {
+        self.par_iter_mut().for_each(|it| {
+            f(it);
+        });
+        self
+    }
+}
+
+impl<K, V, S> ParApplicable for HashMap<K, V, S>
+where
+    K: Eq + Hash + Sync,
+    V: Send,
+    S: BuildHasher,
+{
+    type Item = V;
+
+    fn par_apply_each<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut V) -> R + Sync + Send,
+    {
+        let mut receiver = self;
+        receiver.par_iter_mut().for_each(|(_, it)| {
+            f(it);
+        });
+        receiver
+    }
+}
+
+impl<K, V> ParApplicable for BTreeMap<K, V>
+where
+    K: Ord + Sync,
+    V: Send,
+{
+    type Item = V;
+
+    fn par_apply_each<F, R>(self, f: F) -> Self
+    where
+        F: Fn(&mut V) -> R + Sync + Send,
+    {
+        let mut receiver = self;
+        receiver.par_iter_mut().for_each(|(_, it)| {
+            f(it);
+        });
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Applicable;
+
+    #[test]
+    fn test_par_apply_each_vec() {
+        let numbers = vec![1, 2, 3].par_apply_each(|it| *it *= 2);
+        assert_eq!(numbers, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_par_apply_each_slice() {
+        let mut numbers = [1, 2, 3, 4];
+        numbers[1..3].par_apply_each(|it| *it = 0);
+        assert_eq!(numbers, [1, 0, 0, 4]);
+    }
+
+    #[test]
+    fn test_par_apply_each_map() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, String::from("one!"));
+        exact_map.insert(2, String::from("two!"));
+        let mut map = HashMap::new();
+        map.insert(1, String::from("one"));
+        map.insert(2, String::from("two"));
+        let map = map.par_apply_each(|it| it.push('!'));
+        assert_eq!(map, exact_map);
+
+        let map = BTreeMap::new()
+            .apply(|it| it.insert(1, 1))
+            .par_apply_each(|it| *it += 1);
+        assert_eq!(map[&1], 2);
+    }
+}
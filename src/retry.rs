@@ -0,0 +1,187 @@
+use core::convert::TryFrom;
+use core::time::Duration;
+
+/// The delay inserted between attempts of a retried application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Wait for the same duration before every retry.
+    Fixed(Duration),
+    /// Wait for the initial duration before the first retry, and double it for every next retry.
+    Exponential {
+        /// The delay before the first retry.
+        initial: Duration,
+        /// The upper limit of the delay.
+        max: Duration,
+    },
+}
+
+/// The policy which controls how many times and how often a failed application is retried.
+///
+/// The function is always tried at least once, so `max_attempts` of zero is treated as one.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use core::time::Duration;
+/// let policy = RetryPolicy::exponential(4, Duration::from_millis(10));
+/// assert_eq!(policy.max_attempts(), 4);
+/// assert_eq!(policy.delay(0), Duration::from_millis(10));
+/// assert_eq!(policy.delay(2), Duration::from_millis(40));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    backoff: Backoff,
+}
+
+impl RetryPolicy {
+    /// Create a policy which tries at most `max_attempts` times without waiting.
+    pub fn immediate(max_attempts: usize) -> Self {
+        Self::fixed(max_attempts, Duration::from_secs(0))
+    }
+
+    /// Create a policy which tries at most `max_attempts` times, waiting for `delay` between
+    /// attempts.
+    pub fn fixed(max_attempts: usize, delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Backoff::Fixed(delay),
+        }
+    }
+
+    /// Create a policy which tries at most `max_attempts` times, doubling the delay from
+    /// `initial` between attempts.
+    pub fn exponential(max_attempts: usize, initial: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Backoff::Exponential {
+                initial,
+                max: Duration::MAX,
+            },
+        }
+    }
+
+    /// Limit the delay of the exponential backoff to `max`.
+    pub fn with_max_delay(self, max: Duration) -> Self {
+        let backoff = match self.backoff {
+            Backoff::Fixed(delay) => Backoff::Fixed(delay.min(max)),
+            Backoff::Exponential { initial, .. } => Backoff::Exponential { initial, max },
+        };
+        Self { backoff, ..self }
+    }
+
+    /// The maximum number of attempts, including the first one.
+    ///
+    /// This is at least one, since a policy created with zero is clamped to one.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// The backoff between attempts.
+    pub fn backoff(&self) -> Backoff {
+        self.backoff
+    }
+
+    /// The delay before the retry following the given zero-based failed attempt.
+    pub fn delay(&self, attempt: usize) -> Duration {
+        match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, max } => {
+                let factor = u32::try_from(attempt)
+                    .ok()
+                    .and_then(|it| 1u32.checked_shl(it));
+                factor
+                    .and_then(|it| initial.checked_mul(it))
+                    .map_or(max, |it| it.min(max))
+            }
+        }
+    }
+
+    /// Call the fallible function until it succeeds or the attempts run out, sleeping between
+    /// attempts, and return the result of the last attempt.
+    #[cfg(feature = "std")]
+    pub(crate) fn retry<R, E, F>(&self, mut f: F) -> Result<R, E>
+    where
+        F: FnMut() -> Result<R, E>,
+    {
+        let mut attempt = 0;
+        loop {
+            match f() {
+                Err(_) if attempt + 1 < self.max_attempts => {
+                    let delay = self.delay(attempt);
+                    if delay != Duration::ZERO {
+                        std::thread::sleep(delay);
+                    }
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_fixed_delay() {
+        let policy = RetryPolicy::fixed(3, Duration::from_millis(5));
+        assert_eq!(policy.max_attempts(), 3);
+        assert_eq!(policy.delay(0), Duration::from_millis(5));
+        assert_eq!(policy.delay(10), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_retry_policy_exponential_delay() {
+        let policy = RetryPolicy::exponential(3, Duration::from_millis(5))
+            .with_max_delay(Duration::from_millis(30));
+        assert_eq!(policy.delay(0), Duration::from_millis(5));
+        assert_eq!(policy.delay(1), Duration::from_millis(10));
+        assert_eq!(policy.delay(2), Duration::from_millis(20));
+        assert_eq!(policy.delay(3), Duration::from_millis(30));
+        assert_eq!(policy.delay(100), Duration::from_millis(30));
+        let policy = RetryPolicy::exponential(3, Duration::from_nanos(1));
+        assert_eq!(policy.delay(40), Duration::MAX);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_retry_policy_retry() {
+        let policy = RetryPolicy::immediate(3);
+        let mut attempts = 0;
+        let result: Result<(), usize> = policy.retry(|| {
+            attempts += 1;
+            Err(attempts)
+        });
+        assert_eq!(result, Err(3));
+        let mut attempts = 0;
+        let result = policy.retry(|| {
+            attempts += 1;
+            if attempts < 2 {
+                Err(())
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result, Ok(2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_retry_policy_zero_attempts() {
+        let policy = RetryPolicy::immediate(0);
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(
+            RetryPolicy::exponential(0, Duration::from_millis(5)).max_attempts(),
+            1
+        );
+        let mut attempts = 0;
+        let result: Result<(), usize> = policy.retry(|| {
+            attempts += 1;
+            Err(attempts)
+        });
+        assert_eq!(result, Err(1));
+    }
+}
@@ -0,0 +1,54 @@
+//! The abstraction over the asynchronous runtimes.
+//!
+//! tokio is used if both of the `tokio` and `async-std` features are enabled.
+
+use core::future::Future;
+use core::time::Duration;
+
+/// Await the future, returning None if it does not complete within the duration.
+#[cfg(feature = "tokio")]
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    tokio::time::timeout(duration, future).await.ok()
+}
+
+/// Await the future, returning None if it does not complete within the duration.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    async_std::future::timeout(duration, future).await.ok()
+}
+
+/// Wait until the duration has elapsed.
+#[cfg(feature = "tokio")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Wait until the duration has elapsed.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
+
+/// Run the blocking function on the blocking thread pool, propagating its panic.
+#[cfg(feature = "tokio")]
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(error) => panic!("{}", error),
+    }
+}
+
+/// Run the blocking function on the blocking thread pool, propagating its panic.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    async_std::task::spawn_blocking(f).await
+}
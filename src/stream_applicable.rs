@@ -0,0 +1,115 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+
+pin_project_lite::pin_project! {
+    /// The stream returned by [`StreamApplicable::map_apply`].
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MapApply<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F, R> Stream for MapApply<S, F>
+where
+    S: Stream,
+    F: FnMut(&mut S::Item) -> R,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream.poll_next(cx).map(|item| {
+            item.map(|mut item| {
+                f(&mut item);
+                item
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Allows you to apply any function given as a parameter to every item of the stream.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use futures::StreamExt;
+/// use std::path::PathBuf;
+/// # futures::executor::block_on(async {
+/// let paths: Vec<PathBuf> = futures::stream::iter(vec![PathBuf::from("src/lib")])
+///     .map_apply(|it| it.set_extension("rs"))
+///     .collect()
+///     .await;
+/// assert_eq!(paths, vec![PathBuf::from("src/lib.rs")]);
+/// # });
+/// ```
+///
+pub trait StreamApplicable: Stream + Sized {
+    /// Return the stream which yields the items of self with the function given as a parameter
+    /// applied to each of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use futures::StreamExt;
+    /// # futures::executor::block_on(async {
+    /// let numbers: Vec<Vec<i32>> = futures::stream::iter(vec![vec![2, 1], vec![4, 3]])
+    ///     .map_apply(|it| it.sort())
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(numbers, vec![vec![1, 2], vec![3, 4]]);
+    /// # });
+    /// ```
+    fn map_apply<F, R>(self, f: F) -> MapApply<Self, F>
+    where
+        F: FnMut(&mut Self::Item) -> R;
+}
+
+impl<T: Stream> StreamApplicable for T {
+    fn map_apply<F, R>(self, f: F) -> MapApply<Self, F>
+    where
+        F: FnMut(&mut Self::Item) -> R,
+    {
+        MapApply { stream: self, f }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    #[test]
+    fn test_map_apply() {
+        let numbers: Vec<i32> = block_on(
+            futures::stream::iter(vec![1, 2, 3])
+                .map_apply(|it| *it *= 2)
+                .collect(),
+        );
+        assert_eq!(numbers, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_map_apply_stateful_function() {
+        let mut count = 0;
+        let numbers: Vec<Vec<i32>> = block_on(
+            futures::stream::iter(vec![vec![], vec![], vec![]])
+                .map_apply(|it| {
+                    count += 1;
+                    it.push(count)
+                })
+                .collect(),
+        );
+        assert_eq!(numbers, vec![vec![1], vec![2], vec![3]]);
+    }
+}
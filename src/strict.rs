@@ -0,0 +1,22 @@
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for () {}
+}
+
+/// The marker of the return types accepted by
+/// [`Applicable::apply_strict`](crate::Applicable::apply_strict).
+///
+/// It is sealed and only implemented for `()`, so a function whose result would be silently
+/// discarded, like `HashMap::insert` returning the old value, is rejected at compile time.
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use apply_method::*;
+/// use std::collections::HashMap;
+/// let map = HashMap::new().apply_strict(|it| it.insert(1, "one"));
+/// ```
+pub trait UnitReturn: private::Sealed {}
+
+impl UnitReturn for () {}
@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::mem;
+use std::thread::LocalKey;
+
+struct Restore<T: 'static> {
+    key: &'static LocalKey<RefCell<T>>,
+    previous: Option<T>,
+}
+
+impl<T: 'static> Drop for Restore<T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            // The value cannot be restored once the thread-local itself is being destroyed.
+            let _ = self.key.try_with(|cell| *cell.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Apply the function given as a parameter to the thread-local value for the duration of the
+/// scope, and restore the previous value afterwards.
+///
+/// The previous value is restored even if the scope panics, so this is useful to override
+/// thread-local configuration per test or per request.
+///
+/// # Panics
+///
+/// Panics if the thread-local value is already borrowed when it is modified or restored.
+///
+/// # Example
+///
+/// ```
+/// use apply_method::*;
+/// use std::cell::RefCell;
+/// thread_local! {
+///     static VERBOSE: RefCell<bool> = RefCell::new(false);
+/// }
+/// let verbose = apply_scoped_tls(&VERBOSE, |it| *it = true, || VERBOSE.with(|it| *it.borrow()));
+/// assert!(verbose);
+/// assert!(!VERBOSE.with(|it| *it.borrow()));
+/// ```
+pub fn apply_scoped_tls<T, F, R, S, U>(key: &'static LocalKey<RefCell<T>>, f: F, scope: S) -> U
+where
+    T: Clone + 'static,
+    F: FnOnce(&mut T) -> R,
+    S: FnOnce() -> U,
+{
+    let previous = key.with(|cell| {
+        let mut value = cell.borrow_mut();
+        let mut applied = value.clone();
+        f(&mut applied);
+        mem::replace(&mut *value, applied)
+    });
+    let _restore = Restore {
+        key,
+        previous: Some(previous),
+    };
+    scope()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    thread_local! {
+        static LEVEL: RefCell<i32> = const { RefCell::new(1) };
+        static NUMBERS: RefCell<Vec<i32>> = RefCell::new(vec![1]);
+    }
+
+    #[test]
+    fn test_apply_scoped_tls() {
+        let level = apply_scoped_tls(&LEVEL, |it| *it += 1, || LEVEL.with(|it| *it.borrow()));
+        assert_eq!(level, 2);
+        assert_eq!(LEVEL.with(|it| *it.borrow()), 1);
+    }
+
+    #[test]
+    fn test_apply_scoped_tls_panicked_case() {
+        let result = panic::catch_unwind(|| {
+            apply_scoped_tls(&NUMBERS, |it| it.push(2), || panic!("fail in the scope"))
+        });
+        assert!(result.is_err());
+        assert_eq!(NUMBERS.with(|it| it.borrow().clone()), vec![1]);
+    }
+}
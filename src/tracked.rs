@@ -0,0 +1,97 @@
+use core::ops::{Deref, DerefMut};
+
+/// The wrapper which records whether the value has been accessed mutably since the last reset.
+///
+/// Every mutable access through `DerefMut` marks the value as dirty, so the functions applied by
+/// `Applicable` to the wrapper are tracked without any extra call.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let mut names = Tracked::new(vec!["Pochi"]);
+/// assert!(!names.is_dirty());
+/// names = names.apply(|it| it.push("Hachi"));
+/// assert!(names.take_dirty());
+/// assert!(!names.is_dirty());
+/// assert_eq!(names.len(), 2);
+/// assert!(!names.is_dirty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Tracked<T> {
+    value: T,
+    dirty: bool,
+}
+
+impl<T> Tracked<T> {
+    /// Create a new wrapper which is not dirty.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            dirty: false,
+        }
+    }
+
+    /// Return true if the value has been accessed mutably since the last reset.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Return whether the value is dirty, and reset the flag.
+    pub fn take_dirty(&mut self) -> bool {
+        core::mem::replace(&mut self.dirty, false)
+    }
+
+    /// Reset the flag, so that the value is not dirty.
+    pub fn reset(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Unwrap the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.dirty = true;
+        &mut self.value
+    }
+}
+
+impl<T> From<T> for Tracked<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Applicable;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_tracked() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let mut map = Tracked::new(HashMap::new());
+        assert!(!map.is_dirty());
+        map = map.apply(|it| it.insert(1, "one"));
+        assert!(map.is_dirty());
+        map.reset();
+        assert!(!map.is_dirty());
+        assert_eq!(map.get(&1), Some(&"one"));
+        assert!(!map.take_dirty());
+        assert_eq!(map.into_inner(), exact_map);
+    }
+}
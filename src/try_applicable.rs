@@ -0,0 +1,184 @@
+use crate::ApplyError;
+
+/// Allows you to apply fallible functions to the object, unifying their errors into one type.
+///
+/// The functions given as parameters may return any error which can be converted into `E`, so
+/// the steps of a chain can fail with different error types like `?` does. As `E` is decided by
+/// type inference, connect the steps with `and_then` so the final type determines it.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::num::ParseIntError;
+/// use std::str::Utf8Error;
+///
+/// #[derive(Debug)]
+/// enum ConfigError {
+///     Utf8(Utf8Error),
+///     Parse(ParseIntError),
+/// }
+/// impl From<Utf8Error> for ConfigError {
+///     fn from(e: Utf8Error) -> Self {
+///         ConfigError::Utf8(e)
+///     }
+/// }
+/// impl From<ParseIntError> for ConfigError {
+///     fn from(e: ParseIntError) -> Self {
+///         ConfigError::Parse(e)
+///     }
+/// }
+///
+/// fn load(name: &[u8], port: &str) -> Result<(String, u16), ConfigError> {
+///     (String::new(), 0)
+///         .try_apply_as(|it| std::str::from_utf8(name).map(|s| it.0 = s.to_string()))
+///         .and_then(|it| it.try_apply_as(|it| port.parse().map(|p| it.1 = p)))
+/// }
+/// assert_eq!(load(b"localhost", "8080").unwrap(), ("localhost".to_string(), 8080));
+/// assert!(matches!(load(&[0xff], "8080"), Err(ConfigError::Utf8(_))));
+/// assert!(matches!(load(b"localhost", "port"), Err(ConfigError::Parse(_))));
+/// ```
+///
+pub trait TryApplicable<E>: Sized {
+    /// Apply the fallible function given as a parameter to self, converting its error into `E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers: Result<Vec<i32>, Box<dyn std::error::Error>> =
+    ///     Vec::new().try_apply_as(|it| "1".parse::<i32>().map(|n| it.push(n)));
+    /// assert_eq!(numbers.unwrap(), vec![1]);
+    /// ```
+    fn try_apply_as<F, R, X>(self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self) -> Result<R, X>,
+        X: Into<E>;
+
+    /// Apply the fallible function with one parameter given as a parameter to self, converting
+    /// its error into `E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers: Result<Vec<u8>, Box<dyn std::error::Error>> =
+    ///     Vec::new().try_apply_with_param_as(Vec::try_reserve, 10);
+    /// assert!(numbers.unwrap().capacity() >= 10);
+    /// ```
+    fn try_apply_with_param_as<F, P, R, X>(self, f: F, p: P) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self, P) -> Result<R, X>,
+        X: Into<E>;
+
+    /// Apply try_apply_with_param_as repeatedly to multiple parameters.
+    ///
+    /// The application stops at the first failing parameter, and the converted error is returned
+    /// with the position of that parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// let numbers: Result<Vec<i32>, ApplyError<Box<dyn std::error::Error>>> = Vec::new()
+    ///     .try_apply_with_params_as(|it, s: &str| s.parse().map(|n| it.push(n)), vec!["1", "x"]);
+    /// assert_eq!(numbers.unwrap_err().index, 1);
+    /// ```
+    fn try_apply_with_params_as<F, P, I, R, X>(self, f: F, p: I) -> Result<Self, ApplyError<E>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, X>,
+        X: Into<E>;
+}
+
+impl<T, E> TryApplicable<E> for T {
+    fn try_apply_as<F, R, X>(self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self) -> Result<R, X>,
+        X: Into<E>,
+    {
+        let mut receiver = self;
+        f(&mut receiver).map_err(Into::into)?;
+        Ok(receiver)
+    }
+
+    fn try_apply_with_param_as<F, P, R, X>(self, f: F, p: P) -> Result<Self, E>
+    where
+        F: FnOnce(&mut Self, P) -> Result<R, X>,
+        X: Into<E>,
+    {
+        let mut receiver = self;
+        f(&mut receiver, p).map_err(Into::into)?;
+        Ok(receiver)
+    }
+
+    fn try_apply_with_params_as<F, P, I, R, X>(self, f: F, p: I) -> Result<Self, ApplyError<E>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&mut Self, P) -> Result<R, X>,
+        X: Into<E>,
+    {
+        let mut receiver = self;
+        for (index, param) in p.into_iter().enumerate() {
+            f(&mut receiver, param).map_err(|source| ApplyError::new(index, source.into()))?;
+        }
+        Ok(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::{ParseFloatError, ParseIntError};
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Int(ParseIntError),
+        Float(ParseFloatError),
+    }
+
+    impl From<ParseIntError> for TestError {
+        fn from(e: ParseIntError) -> Self {
+            TestError::Int(e)
+        }
+    }
+
+    impl From<ParseFloatError> for TestError {
+        fn from(e: ParseFloatError) -> Self {
+            TestError::Float(e)
+        }
+    }
+
+    fn parse(int: &str, float: &str) -> Result<(i32, f64), TestError> {
+        (0, 0.0)
+            .try_apply_as(|it| int.parse().map(|n| it.0 = n))
+            .and_then(|it| it.try_apply_as(|it| float.parse().map(|n| it.1 = n)))
+    }
+
+    #[test]
+    fn test_try_apply_as() {
+        assert_eq!(parse("1", "2.5"), Ok((1, 2.5)));
+        assert!(matches!(parse("x", "2.5"), Err(TestError::Int(_))));
+        assert!(matches!(parse("1", "x"), Err(TestError::Float(_))));
+    }
+
+    #[test]
+    fn test_try_apply_with_param_as() {
+        let numbers: Result<Vec<i32>, TestError> = Vec::new().try_apply_with_param_as(
+            |it: &mut Vec<i32>, s: &str| s.parse().map(|n| it.push(n)),
+            "1",
+        );
+        assert_eq!(numbers, Ok(vec![1]));
+    }
+
+    #[test]
+    fn test_try_apply_with_params_as() {
+        let numbers: Result<Vec<i32>, ApplyError<TestError>> = Vec::new().try_apply_with_params_as(
+            |it: &mut Vec<i32>, s: &str| s.parse().map(|n| it.push(n)),
+            vec!["1", "2", "x"],
+        );
+        let error = numbers.unwrap_err();
+        assert_eq!(error.index, 2);
+        assert!(matches!(error.source, TestError::Int(_)));
+    }
+}
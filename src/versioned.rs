@@ -0,0 +1,100 @@
+use core::ops::Deref;
+
+/// The wrapper which increments its version every time a function is applied.
+///
+/// The value can only be mutated through the apply methods of this type, so caches and sync
+/// layers can detect staleness by comparing [`Versioned::version`] without hashing the value.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// let names = Versioned::new(vec!["Pochi"]);
+/// let cached = names.version();
+/// let mut names = names.apply(|it| it.push("Hachi"));
+/// assert_ne!(names.version(), cached);
+/// names.apply_mut(|it| it.sort());
+/// assert_eq!(names.version(), 2);
+/// assert_eq!(*names, vec!["Hachi", "Pochi"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Versioned<T> {
+    value: T,
+    version: u64,
+}
+
+impl<T> Versioned<T> {
+    /// Create a new wrapper with the version zero.
+    pub fn new(value: T) -> Self {
+        Self { value, version: 0 }
+    }
+
+    /// Return the number of the functions applied so far.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Apply the function given as a parameter to the value, increment the version, and return
+    /// self.
+    pub fn apply<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut receiver = self;
+        receiver.apply_mut(f);
+        receiver
+    }
+
+    /// Apply the function given as a parameter to the value in place, increment the version, and
+    /// return the mutable reference to self.
+    ///
+    /// This deliberately shadows [`ApplicableMut::apply_mut`](crate::ApplicableMut::apply_mut),
+    /// which would pass the wrapper itself to the function, so that calling `apply_mut` on the
+    /// wrapper always increments the version.
+    pub fn apply_mut<F, R>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut self.value);
+        self.version += 1;
+        self
+    }
+
+    /// Unwrap the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Versioned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_versioned() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        exact_map.insert(2, "two");
+        let mut map = Versioned::new(HashMap::new()).apply(|it| it.insert(1, "one"));
+        assert_eq!(map.version(), 1);
+        map.apply_mut(|it| it.insert(2, "two"))
+            .apply_mut(|it| it.remove(&3));
+        assert_eq!(map.version(), 3);
+        assert_eq!(map.into_inner(), exact_map);
+    }
+}
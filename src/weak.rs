@@ -0,0 +1,105 @@
+use alloc::rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync;
+
+/// Allows you to apply any function given as a parameter to the target of a weak pointer if it
+/// is still alive.
+///
+/// This is useful for observer lists holding weak references to the shared state. Combined with
+/// the extensions for interior mutability, the shared state can be updated with one line.
+///
+/// # Examples
+///
+/// ```
+/// use apply_method::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// let alive = Rc::new(RefCell::new(Vec::new()));
+/// let dropped = Rc::new(RefCell::new(Vec::new()));
+/// let observers = vec![Rc::downgrade(&alive), Rc::downgrade(&dropped)];
+/// drop(dropped);
+/// let notified = observers
+///     .iter()
+///     .filter_map(|it| {
+///         it.apply_weak(|it| {
+///             it.apply_interior(|it| it.push("updated"));
+///         })
+///     })
+///     .count();
+/// assert_eq!(notified, 1);
+/// assert_eq!(*alive.borrow(), vec!["updated"]);
+/// ```
+///
+pub trait WeakApplicable<T: ?Sized> {
+    /// Upgrade self, and apply the function given as a parameter to the target if it is still
+    /// alive.
+    ///
+    /// Return the result of the function, or `None` if the target has already been dropped and
+    /// the function was not applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apply_method::*;
+    /// use std::sync::{Arc, Mutex};
+    /// let count = Arc::new(Mutex::new(0));
+    /// let weak = Arc::downgrade(&count);
+    /// assert!(weak.apply_weak(|it| *it.lock().unwrap() += 1).is_some());
+    /// drop(count);
+    /// assert!(weak.apply_weak(|it| *it.lock().unwrap() += 1).is_none());
+    /// ```
+    fn apply_weak<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R;
+}
+
+impl<T: ?Sized> WeakApplicable<T> for rc::Weak<T> {
+    fn apply_weak<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.upgrade().map(|it| f(&it))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: ?Sized> WeakApplicable<T> for sync::Weak<T> {
+    fn apply_weak<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.upgrade().map(|it| f(&it))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_apply_weak_rc() {
+        let mut exact_map = HashMap::new();
+        exact_map.insert(1, "one");
+        let map = Rc::new(RefCell::new(HashMap::new()));
+        let weak = Rc::downgrade(&map);
+        let result = weak.apply_weak(|it| it.borrow_mut().insert(1, "one"));
+        assert_eq!(result, Some(None));
+        assert_eq!(*map.borrow(), exact_map);
+        drop(map);
+        assert_eq!(weak.apply_weak(|it| it.borrow_mut().insert(2, "two")), None);
+    }
+
+    #[test]
+    fn test_apply_weak_arc() {
+        let numbers = Arc::new(Mutex::new(vec![1]));
+        let weak = Arc::downgrade(&numbers);
+        assert_eq!(weak.apply_weak(|it| it.lock().unwrap().push(2)), Some(()));
+        assert_eq!(*numbers.lock().unwrap(), vec![1, 2]);
+        drop(numbers);
+        assert_eq!(weak.apply_weak(|it| it.lock().unwrap().push(3)), None);
+    }
+}